@@ -0,0 +1,104 @@
+// mealgen-lint — checks the pairing graph before a data change ships
+//
+// Usage: mealgen-lint [--json REPORT.json] [--strict] [PACK.json ...]
+//
+// Lints the built-in catalog with any given content packs merged on top and
+// prints the report; --json also writes it as JSON to REPORT.json. Notes
+// never fail the run. Exits 1 when errors are found (or warnings, with --strict) and 2 when the
+// arguments can't be used.
+
+use std::process::ExitCode;
+
+use mealgen::content::{catalog, lint_catalog, merge_pack, parse_pack, Check, LintReport, Severity};
+
+struct Args {
+    json: Option<String>,
+    strict: bool,
+    packs: Vec<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args { json: None, strict: false, packs: Vec::new() };
+    let mut argv = std::env::args().skip(1);
+    while let Some(arg) = argv.next() {
+        match arg.as_str() {
+            "--json" => args.json = Some(argv.next().ok_or("--json needs a file to write")?),
+            "--strict" => args.strict = true,
+            "-h" | "--help" => return Err(String::new()),
+            flag if flag.starts_with("--") => return Err(format!("unknown flag {flag}")),
+            _ => args.packs.push(arg),
+        }
+    }
+    Ok(args)
+}
+
+fn print_human(report: &LintReport, packs: &[String]) {
+    println!(
+        "mealgen-lint: {} ingredients, {} cuisines{}",
+        report.ingredients,
+        report.cuisines,
+        if packs.is_empty() { String::new() } else { format!(" (packs: {})", packs.join(", ")) }
+    );
+    for severity in [Severity::Error, Severity::Warning, Severity::Note] {
+        for f in report.findings.iter().filter(|f| f.severity == severity) {
+            let tag = match severity {
+                Severity::Error => "error  ",
+                Severity::Warning => "warning",
+                Severity::Note => "note   ",
+            };
+            println!("{tag} [{}] {}", f.check.id(), f.message);
+        }
+    }
+    println!("{} errors, {} warnings, {} notes", report.errors, report.warnings, report.notes);
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(msg) => {
+            if !msg.is_empty() {
+                eprintln!("mealgen-lint: {msg}");
+            }
+            eprintln!("usage: mealgen-lint [--json REPORT.json] [--strict] [PACK.json ...]");
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut report = LintReport::default();
+    let mut merged = catalog().clone();
+    for path in &args.packs {
+        let json = match std::fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(e) => {
+                eprintln!("mealgen-lint: {path}: {e}");
+                return ExitCode::from(2);
+            }
+        };
+        match parse_pack(&json).map_err(|e| vec![e]).and_then(|pack| merge_pack(&merged, &pack)) {
+            Ok(next) => merged = next,
+            Err(errors) => {
+                for e in errors {
+                    report.error(Check::InvalidPack, format!("{path}: {e}"));
+                }
+            }
+        }
+    }
+    lint_catalog(&merged, &mut report);
+
+    print_human(&report, &merged.packs);
+    if let Some(path) = &args.json {
+        let written = serde_json::to_string_pretty(&report)
+            .map_err(|e| e.to_string())
+            .and_then(|out| std::fs::write(path, out + "\n").map_err(|e| e.to_string()));
+        if let Err(e) = written {
+            eprintln!("mealgen-lint: {path}: {e}");
+            return ExitCode::from(2);
+        }
+    }
+
+    if report.errors > 0 || (args.strict && report.warnings > 0) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
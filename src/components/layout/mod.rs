@@ -12,6 +12,7 @@ pub fn Nav() -> Element {
             }
             div { class: "nav__links",
                 a { class: "nav__link nav__link--active", href: "/", "Generator" }
+                a { class: "nav__link", href: "/plan", "Week" }
             }
         }
     }
@@ -0,0 +1,241 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::content::{catalog, Category, Ingredient, SERVINGS};
+
+use super::meal_types::MealSelection;
+use super::storage;
+
+const STORAGE_KEY: &str = "mealgen.budget";
+/// Largest price or cap the inputs take, $10,000; anything bigger is a typo.
+const MAX_CENTS: u32 = 1_000_000;
+
+/// The household's own prices and per-meal spending cap.
+pub static BUDGET: GlobalSignal<Budget> = Signal::global(Budget::load);
+
+/// Dinner cost for four, and the items that had no price to count.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct MealCost {
+    pub cents: u32,
+    pub unpriced: Vec<&'static str>,
+}
+
+impl MealCost {
+    pub const fn per_serving(&self) -> u32 {
+        self.cents.div_ceil(SERVINGS)
+    }
+}
+
+/// The household's prices and spending cap. Prices override the catalog's
+/// and are keyed by ingredient ID as a string, like history.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct Budget {
+    pub max_cents: Option<u32>,
+    pub prices: Vec<(String, u32)>,
+}
+
+impl Budget {
+    pub fn own_price(&self, i: &Ingredient) -> Option<u32> {
+        self.prices.iter().find(|(id, _)| id == i.id.as_str()).map(|(_, c)| *c)
+    }
+
+    /// Cents for one `buy_amount` of `i`, the household's price first.
+    pub fn price(&self, i: &Ingredient) -> Option<u32> {
+        self.own_price(i).or_else(|| catalog().price(i.id))
+    }
+
+    /// Sets the household price for `i`; `None` goes back to the catalog's.
+    pub fn set_price(&mut self, i: &Ingredient, cents: Option<u32>) {
+        self.prices.retain(|(id, _)| id != i.id.as_str());
+        if let Some(c) = cents {
+            self.prices.push((i.id.as_str().to_string(), c));
+        }
+    }
+
+    pub fn meal_cost(&self, sel: &MealSelection) -> MealCost {
+        let mut out = MealCost::default();
+        for i in [sel.protein, sel.starch, sel.veg1, sel.veg2].into_iter().flatten() {
+            match self.price(i) {
+                Some(c) => out.cents = out.cents.saturating_add(c),
+                None => out.unpriced.push(i.name),
+            }
+        }
+        out
+    }
+
+    /// How far the plate, plus `extra` cents still to come, runs past the
+    /// cap; zero when it fits or there's none.
+    pub fn cents_over(&self, sel: &MealSelection, extra: u32) -> u32 {
+        self.max_cents.map_or(0, |max| self.meal_cost(sel).cents.saturating_add(extra).saturating_sub(max))
+    }
+
+    /// The lowest price in `pool`.
+    pub fn cheapest(&self, pool: &[&'static Ingredient]) -> u32 {
+        pool.iter().filter_map(|i| self.price(i)).min().unwrap_or(0)
+    }
+
+    pub fn load() -> Self {
+        storage::read_json(STORAGE_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        storage::write_json(STORAGE_KEY, self);
+    }
+}
+
+pub fn format_cents(cents: u32) -> String {
+    format!("${}.{:02}", cents / 100, cents % 100)
+}
+
+/// Reads "12.5" or "$12.50" as cents, capped at `MAX_CENTS`; blank,
+/// negative or anything but a plain decimal is `None`.
+fn parse_dollars(s: &str) -> Option<u32> {
+    let s = s.trim().trim_start_matches('$');
+    // f64 would also take "inf", "NaN", "1e9" and signs
+    if !s.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+        return None;
+    }
+    let dollars: f64 = s.parse().ok()?;
+    let cents = (dollars * 100.0).round().min(f64::from(MAX_CENTS));
+    // Finite, capped and non-negative, so it fits u32 cents exactly
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Some(cents as u32)
+}
+
+/// Cost line shown with the meal description.
+#[component]
+pub fn CostLine(budget: Signal<Budget>, selection: MealSelection) -> Element {
+    let b = budget.read();
+    let cost = b.meal_cost(&selection);
+    if cost.cents == 0 {
+        return rsx! {};
+    }
+    let over = b.max_cents.filter(|&max| cost.cents > max);
+
+    rsx! {
+        p { class: "meal-cost",
+            span { class: "meal-cost__total", "{format_cents(cost.cents)}" }
+            " for {SERVINGS} \u{00B7} {format_cents(cost.per_serving())} a serving"
+            if let Some(max) = over {
+                span { class: "meal-cost__over", " \u{00B7} over the {format_cents(max)} cap" }
+            }
+            if !cost.unpriced.is_empty() {
+                span { class: "meal-cost__missing", " \u{00B7} no price for {cost.unpriced.join(\", \")}" }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn BudgetSettings(budget: Signal<Budget>) -> Element {
+    let b = budget.read().clone();
+    let max = b.max_cents.map(|c| format!("{:.2}", f64::from(c) / 100.0)).unwrap_or_default();
+    let groups = [("Proteins", Category::Protein), ("Starches", Category::Starch), ("Veg", Category::Veg)];
+
+    rsx! {
+        div { class: "household",
+            span { class: "household__title", "Budget" }
+            div { class: "household__row",
+                span { class: "household__label", "Max per meal" }
+                input {
+                    class: "budget__input",
+                    r#type: "number",
+                    min: "0",
+                    step: "0.5",
+                    placeholder: "No cap",
+                    value: "{max}",
+                    onchange: move |e| {
+                        let mut w = budget.write();
+                        w.max_cents = parse_dollars(&e.value());
+                        w.save();
+                    },
+                }
+            }
+            details { class: "pantry",
+                summary { class: "pantry__summary", "Prices" }
+                for (label, cat) in groups {
+                    span { class: "household__label", "{label}" }
+                    ul { class: "pantry__stock",
+                        for i in catalog().by_category(cat) {
+                            {render_price(i, &b, budget)}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_price(i: &'static Ingredient, b: &Budget, mut budget: Signal<Budget>) -> Element {
+    let price = b.price(i).map(|c| format!("{:.2}", f64::from(c) / 100.0)).unwrap_or_default();
+    let per = i.buy_amount.unwrap_or("dinner");
+    let own = b.own_price(i).is_some();
+
+    rsx! {
+        li { class: "pantry__item",
+            span { class: "pantry__name", "{i.name}" }
+            input {
+                class: "pantry__qty",
+                r#type: "number",
+                min: "0",
+                step: "0.01",
+                placeholder: "$",
+                value: "{price}",
+                onchange: move |e| {
+                    let mut w = budget.write();
+                    w.set_price(i, parse_dollars(&e.value()));
+                    w.save();
+                },
+            }
+            span { class: "pantry__unit", "per {per}" }
+            if own {
+                button {
+                    class: "pantry__remove",
+                    title: "Back to the default price",
+                    onclick: move |_| {
+                        let mut w = budget.write();
+                        w.set_price(i, None);
+                        w.save();
+                    },
+                    "\u{21BA}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dollars_parse_to_capped_cents() {
+        assert_eq!(parse_dollars("12.5"), Some(1250));
+        assert_eq!(parse_dollars(" $3.99 "), Some(399));
+        assert_eq!(parse_dollars("99999999999"), Some(MAX_CENTS));
+        assert_eq!(parse_dollars(".5"), Some(50));
+        assert_eq!(parse_dollars("-1"), None);
+        assert_eq!(parse_dollars(""), None);
+        assert_eq!(parse_dollars("."), None);
+        assert_eq!(parse_dollars("1.2.3"), None);
+    }
+
+    #[test]
+    fn only_plain_decimals_parse() {
+        for s in ["inf", "$inf", "-inf", "infinity", "Infinity", "NaN", "nan", "-0", "+5", "1e3", "1E-2", "$-4"] {
+            assert_eq!(parse_dollars(s), None, "{s}");
+        }
+    }
+
+    #[test]
+    fn sums_saturate_instead_of_overflowing() {
+        let Some(&protein) = crate::content::get_proteins().first() else { panic!("no proteins") };
+        let mut budget = Budget { max_cents: Some(100), ..Budget::default() };
+        budget.set_price(protein, Some(u32::MAX));
+        let sel = MealSelection { protein: Some(protein), ..MealSelection::default() };
+        assert_eq!(budget.meal_cost(&sel).cents, u32::MAX);
+        assert_eq!(budget.cents_over(&sel, u32::MAX), u32::MAX - 100);
+    }
+}
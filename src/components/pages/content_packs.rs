@@ -0,0 +1,117 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::content::{catalog, install_catalog, merge_pack, parse_pack, reset_catalog};
+
+/// Lists pack file names (relative to `PACK_BASE_URL`) merged on startup.
+const PACK_MANIFEST_URL: &str = "/assets/data/packs.json";
+const PACK_BASE_URL: &str = "/assets/data/";
+
+/// Bumped whenever the active catalog changes; pages read it to re-render.
+pub static CATALOG_REV: GlobalSignal<u32> = Signal::global(|| 0);
+static PACK_ERRORS: GlobalSignal<Vec<String>> = Signal::global(Vec::new);
+
+fn apply_pack(source: &str, json: &str) {
+    let merged = parse_pack(json)
+        .map_err(|e| vec![e])
+        .and_then(|pack| merge_pack(catalog(), &pack));
+    match merged {
+        Ok(c) => {
+            install_catalog(c);
+            *CATALOG_REV.write() += 1;
+        }
+        Err(errors) => {
+            PACK_ERRORS.write().extend(errors.iter().map(|e| format!("{source}: {e}")));
+        }
+    }
+}
+
+async fn fetch_text(url: &str) -> Option<String> {
+    let window = web_sys::window()?;
+    let resp: web_sys::Response = JsFuture::from(window.fetch_with_str(url))
+        .await
+        .ok()?
+        .dyn_into()
+        .ok()?;
+    if !resp.ok() {
+        return None;
+    }
+    JsFuture::from(resp.text().ok()?).await.ok()?.as_string()
+}
+
+/// Merges every pack listed in the served manifest. A missing manifest just
+/// means the deployment ships no packs.
+pub async fn load_served_packs() {
+    let Some(manifest) = fetch_text(PACK_MANIFEST_URL).await else {
+        return;
+    };
+    let files: Vec<String> = match serde_json::from_str(&manifest) {
+        Ok(files) => files,
+        Err(e) => {
+            PACK_ERRORS.write().push(format!("packs.json: {e}"));
+            return;
+        }
+    };
+    for file in files {
+        match fetch_text(&format!("{PACK_BASE_URL}{file}")).await {
+            Some(json) => apply_pack(&file, &json),
+            None => PACK_ERRORS.write().push(format!("{file}: could not be fetched")),
+        }
+    }
+}
+
+#[component]
+pub fn ContentPacks() -> Element {
+    let _rev = CATALOG_REV();
+    let packs = catalog().packs.clone();
+    let errors = PACK_ERRORS.read().clone();
+
+    rsx! {
+        div { class: "content-packs",
+            span { class: "content-packs__title", "Content Packs" }
+            div { class: "content-packs__list",
+                if packs.is_empty() {
+                    span { class: "content-packs__empty", "Built-in catalog" }
+                }
+                for name in packs.iter() {
+                    span { class: "content-packs__pill", "{name}" }
+                }
+            }
+            div { class: "content-packs__actions",
+                label { class: "btn btn--secondary content-packs__upload",
+                    "Load Pack"
+                    input {
+                        r#type: "file",
+                        accept: ".json,application/json",
+                        onchange: move |evt: FormEvent| async move {
+                            PACK_ERRORS.write().clear();
+                            for file in evt.files() {
+                                match file.read_string().await {
+                                    Ok(json) => apply_pack(&file.name(), &json),
+                                    Err(e) => PACK_ERRORS.write().push(format!("{}: {e}", file.name())),
+                                }
+                            }
+                        },
+                    }
+                }
+                if !packs.is_empty() {
+                    button {
+                        class: "btn btn--secondary",
+                        onclick: move |_| {
+                            reset_catalog();
+                            PACK_ERRORS.write().clear();
+                            *CATALOG_REV.write() += 1;
+                        },
+                        "Reset"
+                    }
+                }
+            }
+            for err in errors.iter() {
+                p { class: "content-packs__error", "{err}" }
+            }
+        }
+    }
+}
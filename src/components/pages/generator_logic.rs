@@ -1,13 +1,125 @@
 #![allow(non_snake_case)]
 
-use crate::content::{Ingredient, CUISINES};
-use super::meal_types::SlotOption;
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::content::{catalog, Archetype, Cuisine, Ingredient, IngredientId};
+use super::quality_engine::cascade_sampled;
+use super::meal_types::{
+    choose_weighted, clear_unlocked_sides, cuisine_conflicts, in_cuisine, pairs_with_sides, sides_paired, side_cuisine, slot_candidates, GenEnv, MealSelection, Pairing, Season, SlotKind,
+    SlotOption,
+};
+use super::preferences::Preferences;
+
+/// The whole outcome of one Generate press.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeneratedMeal {
+    pub cuisine: Cuisine,
+    pub selection: MealSelection,
+    pub archetype: Option<&'static Archetype>,
+    /// Different side combinations the plate was picked from.
+    pub sampled: usize,
+}
+
+/// Builds a plate from `seed`: cuisine (unless `cuisine_locked`), protein,
+/// archetype and sides all come from one seeded RNG, so the same seed, locks,
+/// locked items, history and rules always give the same meal. `env.cuisine`
+/// is used as-is when locked; otherwise a cuisine rule for the day wins when
+/// the household filters leave it a protein. Generation runs from whatever
+/// is locked: with the protein open and sides locked, the protein is drawn
+/// from those that pair with the most locked sides.
+pub fn generate_plate(seed: u64, prev: &MealSelection, env: GenEnv, cuisine_locked: bool) -> GeneratedMeal {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut sel = *prev;
+    clear_unlocked_sides(&mut sel, env.lock);
+    if !env.lock.protein {
+        sel.protein = None;
+    }
+    let cuisine = if cuisine_locked { env.cuisine } else { roll_cuisine(&sel, env, &mut rng) };
+    let env = GenEnv { cuisine, ..env };
+
+    if !env.lock.protein {
+        sel.protein = pick_protein(&sel, env, &mut rng);
+    }
+    let (archetype, sampled) = cascade_sampled(&mut sel, env, &mut rng);
+    GeneratedMeal { cuisine, selection: sel, archetype, sampled }
+}
+
+/// Draws a protein for `sel` in `env.cuisine` from those that fit the
+/// household filters, pair with the most locked sides and stay within the
+/// rules' limits. Shared by generation and the protein reroll.
+pub fn pick_protein(sel: &MealSelection, env: GenEnv, rng: &mut SmallRng) -> Option<&'static Ingredient> {
+    let proteins = env.pool(SlotKind::Protein);
+    let fit = slot_candidates(sel, SlotKind::Protein, env.cuisine, &proteins, None);
+    let options = env.proteins_within_limits(pairs_with_sides(sel, env.cuisine, &fit), env.cuisine);
+    choose_weighted(&options, sel, env, rng)
+}
+
+/// Draws a cuisine for `sel`, which holds only the locked items. Options
+/// must list every locked item and have a protein that pairs with the
+/// locked sides (the locked protein, if there is one); failing that, list
+/// the locked items and have a protein; failing that, just list the locked
+/// items. Only when no cuisine lists them all (the slots then show the
+/// conflict) does any cuisine with a protein do. A protein counts when the
+/// household filters allow it and, if the pantry has one on hand, it's on
+/// hand.
+fn roll_cuisine(sel: &MealSelection, env: GenEnv, rng: &mut SmallRng) -> Cuisine {
+    let proteins = env.pantry.first(&env.pool(SlotKind::Protein));
+    let sides = [sel.starch, sel.veg1, sel.veg2].into_iter().flatten().count();
+    let lists_locked = |c: Cuisine| cuisine_conflicts(sel, env.lock, c).is_empty();
+    let has_protein = |c: Cuisine| !env.proteins_within_limits(in_cuisine(c, &proteins), c).is_empty();
+    let pairs_all = |p: &&'static Ingredient, c: Cuisine| sides_paired(p, sel, c) == sides;
+    let pairs_locked = |c: Cuisine| {
+        if let Some(p) = sel.protein {
+            return pairs_all(&p, c);
+        }
+        let paired: Vec<_> = in_cuisine(c, &proteins).into_iter().filter(|p| pairs_all(p, c)).collect();
+        !env.proteins_within_limits(paired, c).is_empty()
+    };
+    let tiers: [&dyn Fn(Cuisine) -> bool; 4] = [
+        &|c| lists_locked(c) && pairs_locked(c),
+        &|c| lists_locked(c) && has_protein(c),
+        &lists_locked,
+        &has_protein,
+    ];
+    let all = catalog().cuisine_list();
+    let options = tiers
+        .iter()
+        .map(|keep| all.iter().copied().filter(|&c| keep(c)).collect::<Vec<Cuisine>>())
+        .find(|o| !o.is_empty())
+        .unwrap_or_else(|| all.clone());
+
+    match env.rules.cuisine.as_ref().filter(|(c, _)| options.contains(c)) {
+        Some((c, _)) => *c,
+        // Stock that's about to turn pulls its cuisine up with it
+        None => env
+            .history
+            .cuisine_weights(&options)
+            .choose_weighted(rng, |&(c, w)| {
+                w * in_cuisine(c, &proteins).iter().map(|i| env.pantry.urgency(i)).fold(1.0, f64::max)
+            })
+            .map_or(Cuisine::American, |(c, _)| *c),
+    }
+}
+
+/// Mixes `salt` into `seed` (FNV-1a) so follow-up actions on a plate —
+/// rerolls, manual picks — get their own reproducible stream.
+pub fn derive_seed(seed: u64, salt: &str) -> u64 {
+    salt.bytes().fold(seed ^ 0xcbf2_9ce4_8422_2325, |h, b| (h ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3))
+}
+
+pub fn derived_rng(seed: u64, salt: &str) -> SmallRng {
+    SmallRng::seed_from_u64(derive_seed(seed, salt))
+}
 
 pub fn generate_slot_options(
     all_items: &[&'static Ingredient],
     context_protein: Option<&'static Ingredient>,
-    current_cuisine: &'static str,
-    exclude_ids: &[&str],
+    current_cuisine: Cuisine,
+    exclude_ids: &[IngredientId],
+    prefs: &Preferences,
+    season: Season,
 ) -> Vec<SlotOption> {
     all_items.iter()
         .filter(|i| !exclude_ids.contains(&i.id))
@@ -18,13 +130,18 @@ pub fn generate_slot_options(
 
             if let Some(p) = context_protein {
                 // If paired with protein in current cuisine
-                if check_pair(p, i, current_cuisine) {
+                if Pairing::of(p, i, current_cuisine).paired() {
                     is_compatible = true;
                 } else {
                     // Try to find ANY cuisine where they pair
-                    if let Some(c) = find_cuisine_pairing(p, i) {
-                        target_cuisine = Some(c);
-                        // If user picks this, we switch cuisine but keep protein
+                    if let Some(c) = side_cuisine(p, i, current_cuisine) {
+                        if prefs.fusion {
+                            // Fusion plates take the other cuisine's pairing as it is
+                            is_compatible = true;
+                        } else {
+                            // If user picks this, we switch cuisine but keep protein
+                            target_cuisine = Some(c);
+                        }
                     } else {
                         // They don't pair in ANY cuisine.
                         // Check if this item is valid in ANY cuisine at all?
@@ -52,35 +169,227 @@ pub fn generate_slot_options(
                 is_compatible,
                 target_cuisine,
                 clear_protein,
+                blocked: prefs.blocked(i),
+                out_of_season: !season.in_season(i),
             }
         })
         .collect()
 }
 
 // Helpers
-fn check_pair(p: &Ingredient, i: &Ingredient, c: &str) -> bool {
-    // Forward: p lists i in c?
-    let forward = p.cuisines.iter()
-        .find(|(kc, _)| *kc == c)
-        .is_some_and(|(_, list)| list.contains(&i.id));
-    
-    if forward { return true; }
-
-    // Reverse: i lists p in c?
-    i.cuisines.iter()
-        .find(|(kc, _)| *kc == c)
-        .is_some_and(|(_, list)| list.contains(&p.id))
-}
-
-fn is_in_cuisine(i: &Ingredient, c: &str) -> bool {
+fn is_in_cuisine(i: &Ingredient, c: Cuisine) -> bool {
     i.cuisines.iter().any(|(kc, _)| *kc == c)
 }
 
-fn find_cuisine_pairing(p: &Ingredient, i: &Ingredient) -> Option<&'static str> {
-    // Return first cuisine where they pair
-    CUISINES.iter().find(|&c_curr| check_pair(p, i, c_curr)).copied()
+fn find_any_valid_cuisine(i: &Ingredient) -> Option<Cuisine> {
+    i.cuisines.first().map(|(c, _)| *c)
 }
 
-fn find_any_valid_cuisine(i: &Ingredient) -> Option<&'static str> {
-    i.cuisines.first().map(|(c, _)| *c)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::pages::budget::Budget;
+    use crate::components::pages::history::History;
+    use crate::components::pages::meal_types::{fits_archetype, fusion_cuisines, get_item, LockState};
+    use crate::components::pages::pantry::Pantry;
+    use crate::components::pages::rules::MealRules;
+    use crate::content::{Allergen, DietFilter, Lifestyle};
+
+    /// A plate from the built-in catalog under `prefs`, with default
+    /// history, rules, pantry and budget.
+    fn generate(prefs: &Preferences, seed: u64, prev: &MealSelection, lock: LockState) -> GeneratedMeal {
+        let (history, rules, pantry, budget) = (History::default(), MealRules::default(), Pantry::default(), Budget::default());
+        let env = GenEnv {
+            cuisine: Cuisine::American,
+            lock,
+            has_veg2: true,
+            prefs,
+            history: &history,
+            rules: &rules,
+            pantry: &pantry,
+            budget: &budget,
+            season: Season::default(),
+        };
+        generate_plate(seed, prev, env, false)
+    }
+
+    fn items(meal: &GeneratedMeal) -> Vec<&'static Ingredient> {
+        let s = meal.selection;
+        [s.protein, s.starch, s.veg1, s.veg2].into_iter().flatten().collect()
+    }
+
+    /// A plate from the built-in catalog and default settings, as
+    /// (cuisine, item IDs, archetype ID).
+    fn plate(seed: u64, prev: &MealSelection, lock: LockState) -> (&'static str, Vec<&'static str>, Option<&'static str>) {
+        let meal = generate(&Preferences::default(), seed, prev, lock);
+        let s = meal.selection;
+        let items = [s.protein, s.starch, s.veg1, s.veg2].into_iter().flatten().map(|i| i.id.as_str()).collect();
+        (meal.cuisine.id(), items, meal.archetype.map(|a| a.id))
+    }
+
+    /// These pin the generator's output: a change that moves them changes
+    /// what every saved seed replays to, so update them deliberately.
+    #[test]
+    fn fixed_seeds_give_fixed_plates() {
+        let expected = [
+            (0, "asian", vec!["ground_beef", "egg_noodles", "bean_sprouts", "bok_choy"], Some("noodle_stir_fry")),
+            (1, "mediterranean", vec!["chicken_breast", "pita", "cucumber_dill", "roasted_tomatoes"], Some("mezze_plate")),
+            (42, "american", vec!["steak", "roasted_potatoes", "cauliflower", "broccoli"], Some("sheet_pan_roast")),
+            (
+                u64::MAX,
+                "mediterranean",
+                vec!["chicken_breast", "roasted_potatoes", "brussels_sprouts", "spinach"],
+                Some("sheet_pan_mediterranean"),
+            ),
+        ];
+        for (seed, cuisine, items, arch) in expected {
+            assert_eq!(plate(seed, &MealSelection::default(), LockState::default()), (cuisine, items, arch), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn replaying_a_seed_repeats_the_plate() {
+        for seed in [0, 1, 42, u64::MAX] {
+            let a = plate(seed, &MealSelection::default(), LockState::default());
+            assert_eq!(a, plate(seed, &MealSelection::default(), LockState::default()), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn locked_items_survive_generate() {
+        let tortillas = catalog().ingredients.iter().copied().find(|i| i.id.as_str() == "tortillas");
+        let prev = MealSelection { starch: tortillas, ..MealSelection::default() };
+        let lock = LockState { starch: true, ..LockState::default() };
+        for seed in [0, 1, 42] {
+            let (cuisine, items, _) = plate(seed, &prev, lock);
+            assert_eq!(cuisine, "latin", "seed {seed}");
+            assert_eq!(items.get(1), Some(&"tortillas"), "seed {seed}");
+        }
+    }
+
+    /// Rerolls and picks replay from derived seeds, so the mix must not drift.
+    #[test]
+    fn derived_seeds_are_stable() {
+        assert_eq!(derive_seed(42, "reroll-Protein-1"), 1_181_336_008_415_253_129);
+        assert_ne!(derive_seed(42, "reroll-Starch-1"), derive_seed(42, "reroll-Veg1-1"));
+    }
+
+    /// Out of season only weighs a side down, unless the household asks for
+    /// in season only.
+    #[test]
+    fn out_of_season_is_weighted_unless_strict() {
+        let find = |id: &str| catalog().ingredients.iter().copied().find(|i| i.id.as_str() == id);
+        let (Some(sweet_potato), Some(rice)) = (find("sweet_potato"), find("jasmine_rice")) else {
+            panic!("seasonal items missing");
+        };
+        let july = Season { month: 7 };
+        assert!(!july.in_season(sweet_potato));
+        assert!(july.weight(sweet_potato) < july.weight(rice));
+        assert!((Season::default().weight(sweet_potato) - Season::default().weight(rice)).abs() < f64::EPSILON);
+
+        let (history, rules, pantry, budget) = (History::default(), MealRules::default(), Pantry::default(), Budget::default());
+        for in_season_only in [false, true] {
+            let prefs = Preferences { in_season_only, ..Preferences::default() };
+            let env = GenEnv {
+                cuisine: Cuisine::American,
+                lock: LockState::default(),
+                has_veg2: true,
+                prefs: &prefs,
+                history: &history,
+                rules: &rules,
+                pantry: &pantry,
+                budget: &budget,
+                season: july,
+            };
+            assert_eq!(env.pool(SlotKind::Starch).contains(&sweet_potato), !in_season_only);
+        }
+    }
+
+    #[test]
+    fn restricted_diets_keep_excluded_items_off_the_plate() {
+        let diets = [
+            DietFilter { lifestyle: Lifestyle::Vegetarian, ..DietFilter::default() },
+            DietFilter { lifestyle: Lifestyle::Vegan, gluten_free: true, ..DietFilter::default() },
+            DietFilter { lifestyle: Lifestyle::Pescatarian, dairy_free: true, ..DietFilter::default() },
+        ];
+        for diet in diets {
+            let prefs = Preferences { diet, ..Preferences::default() };
+            for seed in 0..40 {
+                let meal = generate(&prefs, seed, &MealSelection::default(), LockState::default());
+                for i in items(&meal) {
+                    assert_eq!(prefs.blocked(i), None, "{} under {} (seed {seed})", i.id.as_str(), diet.describe());
+                }
+            }
+        }
+    }
+
+    /// Allergies are hard: generation never adds an allergen, and a locked
+    /// one stays only because the household kept it.
+    #[test]
+    fn allergens_are_never_generated() {
+        let prefs = Preferences { allergies: vec![Allergen::Fish, Allergen::Shellfish], ..Preferences::default() };
+        let trips = |i: &Ingredient| {
+            catalog().allergens(i.id).is_none_or(|a| a.allergens.iter().any(|x| prefs.allergies.contains(x)))
+        };
+        let caesar = catalog().ingredients.iter().copied().find(|i| i.id.as_str() == "caesar_salad");
+        let kept = MealSelection { veg1: caesar, ..MealSelection::default() };
+        let veg1 = LockState { veg1: true, ..LockState::default() };
+        for seed in 0..60 {
+            let meal = generate(&prefs, seed, &MealSelection::default(), LockState::default());
+            assert!(!items(&meal).into_iter().any(trips), "seed {seed}");
+
+            let meal = generate(&prefs, seed, &kept, veg1);
+            assert_eq!(meal.selection.veg1, caesar, "seed {seed}");
+            let added = [meal.selection.protein, meal.selection.starch, meal.selection.veg2];
+            assert!(!added.into_iter().flatten().any(trips), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn without_fusion_every_side_is_from_the_plates_cuisine() {
+        for seed in 0..40 {
+            let meal = generate(&Preferences::default(), seed, &MealSelection::default(), LockState::default());
+            for side in items(&meal).into_iter().filter(|i| Some(*i) != meal.selection.protein) {
+                assert!(side.cuisines.iter().any(|(c, _)| *c == meal.cuisine), "{} (seed {seed})", side.id.as_str());
+            }
+            assert!(fusion_cuisines(&meal.selection, meal.cuisine).is_empty(), "seed {seed}");
+        }
+    }
+
+    /// A fusion side is one that pairs with the protein only elsewhere, and
+    /// each such cuisine is named on the plate.
+    #[test]
+    fn fusion_sides_come_from_the_cuisines_named() {
+        let prefs = Preferences { fusion: true, ..Preferences::default() };
+        let mut fused = 0;
+        for seed in 0..80 {
+            let meal = generate(&prefs, seed, &MealSelection::default(), LockState::default());
+            let (sel, c) = (meal.selection, meal.cuisine);
+            let Some(p) = sel.protein else { continue };
+            let named = fusion_cuisines(&sel, c);
+            for side in [sel.starch, sel.veg1, sel.veg2].into_iter().flatten() {
+                let k = side_cuisine(p, side, c).unwrap_or_else(|| panic!("{} unpaired (seed {seed})", side.id.as_str()));
+                assert!(k == c || named.contains(&k), "{} from {} (seed {seed})", side.id.as_str(), k.id());
+            }
+            fused += usize::from(!named.is_empty());
+        }
+        assert!(fused > 0, "no seed gave a fusion plate");
+    }
+
+    #[test]
+    fn generated_plates_fit_their_archetype() {
+        let mut built = 0;
+        for seed in 0..60 {
+            let meal = generate(&Preferences::default(), seed, &MealSelection::default(), LockState::default());
+            let Some(a) = meal.archetype else { continue };
+            built += 1;
+            assert_eq!(a.cuisine, meal.cuisine, "seed {seed}");
+            for slot in SlotKind::ALL {
+                if let Some(i) = get_item(&meal.selection, slot) {
+                    assert!(fits_archetype(a, &meal.selection, slot, i), "{} on a {} (seed {seed})", i.id.as_str(), a.id);
+                }
+            }
+        }
+        assert!(built > 0, "no seed built to an archetype");
+    }
 }
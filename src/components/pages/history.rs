@@ -0,0 +1,316 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::content::{catalog, Category, Cuisine, Ingredient};
+
+use super::meal_types::MealSelection;
+use super::storage;
+
+const STORAGE_KEY: &str = "mealgen.history";
+/// Entries older than this are dropped on save.
+const KEEP_DAYS: i64 = 120;
+/// Cuisine deficits are measured over this many days of history.
+const DEFICIT_WINDOW_DAYS: i64 = 28;
+/// A cuisine that's well ahead of its share keeps this much of its weight.
+const DEFICIT_FLOOR: f64 = 0.2;
+
+/// The meals logged with "We're cooking this", read back for cooldowns,
+/// cuisine balancing and the week planner's look back.
+pub static HISTORY: GlobalSignal<History> = Signal::global(History::load);
+
+/// A meal the household said they cooked. IDs are stored as strings so
+/// history survives a content pack being unloaded.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Local days since the Unix epoch.
+    pub day: i64,
+    pub cuisine: String,
+    pub items: Vec<String>,
+}
+
+/// Days an item or cuisine sits out after being cooked; 0 turns it off.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Cooldowns {
+    pub protein: i64,
+    pub starch: i64,
+    pub veg: i64,
+    pub cuisine: i64,
+}
+
+impl Default for Cooldowns {
+    fn default() -> Self {
+        Cooldowns { protein: 3, starch: 1, veg: 0, cuisine: 1 }
+    }
+}
+
+impl Cooldowns {
+    pub const fn for_category(&self, c: Category) -> i64 {
+        match c {
+            Category::Protein => self.protein,
+            Category::Starch => self.starch,
+            Category::Veg => self.veg,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct History {
+    pub cooldowns: Cooldowns,
+    pub entries: Vec<HistoryEntry>,
+    /// Set on load; generation reads cooldowns relative to it.
+    #[serde(skip)]
+    pub today: i64,
+}
+
+impl History {
+    fn within(&self, days: i64) -> impl Iterator<Item = &HistoryEntry> {
+        self.entries.iter().filter(move |e| self.today - e.day < days)
+    }
+
+    pub fn on_cooldown(&self, i: &Ingredient) -> bool {
+        let days = self.cooldowns.for_category(i.category);
+        self.within(days).any(|e| e.items.iter().any(|x| x == i.id.as_str()))
+    }
+
+    pub fn cuisine_on_cooldown(&self, c: Cuisine) -> bool {
+        self.within(self.cooldowns.cuisine).any(|e| e.cuisine == c.id())
+    }
+
+    /// `pool` without anything on cooldown, or all of it when that would
+    /// leave nothing to pick.
+    pub fn fresh(&self, pool: &[&'static Ingredient]) -> Vec<&'static Ingredient> {
+        let fresh: Vec<_> = pool.iter().copied().filter(|i| !self.on_cooldown(i)).collect();
+        if fresh.is_empty() { pool.to_vec() } else { fresh }
+    }
+
+    /// Cuisine roll weights: each option's share of recent meals against an
+    /// even split, so cuisines that have been missing catch up. Options on
+    /// cooldown are left out unless that would leave none.
+    pub fn cuisine_weights(&self, options: &[Cuisine]) -> Vec<(Cuisine, f64)> {
+        let mut open: Vec<Cuisine> = options.iter().copied().filter(|&c| !self.cuisine_on_cooldown(c)).collect();
+        if open.is_empty() {
+            open = options.to_vec();
+        }
+        let recent: Vec<&HistoryEntry> = self.within(DEFICIT_WINDOW_DAYS).collect();
+        let expected = count(recent.len()) / count(options.len().max(1));
+        open.into_iter()
+            .map(|c| {
+                let cooked = count(recent.iter().filter(|e| e.cuisine == c.id()).count());
+                (c, (1.0 + expected - cooked).max(DEFICIT_FLOOR))
+            })
+            .collect()
+    }
+
+    /// Records today's meal, replacing anything already logged for today.
+    pub fn record(&mut self, cuisine: Cuisine, sel: &MealSelection) {
+        let items = [sel.protein, sel.starch, sel.veg1, sel.veg2]
+            .into_iter()
+            .flatten()
+            .map(|i| i.id.as_str().to_string())
+            .collect();
+        self.entries.retain(|e| e.day != self.today);
+        self.entries.push(HistoryEntry { day: self.today, cuisine: cuisine.id().to_string(), items });
+    }
+
+    /// Monday is 0, matching the week planner's days.
+    pub fn weekday(&self) -> usize {
+        // 1970-01-01 was a Thursday
+        usize::try_from((self.today + 3).rem_euclid(7)).unwrap_or(0)
+    }
+
+    /// Meals from the six days before today; with tonight's, a rolling week.
+    pub fn this_week(&self) -> Vec<Vec<&'static Ingredient>> {
+        self.within(7)
+            .filter(|e| e.day != self.today)
+            .map(|e| e.items.iter().filter_map(|id| find_item(id)).collect())
+            .collect()
+    }
+
+    pub fn cooked_today(&self) -> Option<&HistoryEntry> {
+        self.entries.iter().find(|e| e.day == self.today)
+    }
+
+    pub fn load() -> Self {
+        let mut h: History = storage::read_json(STORAGE_KEY).unwrap_or_default();
+        h.today = today();
+        h
+    }
+
+    pub fn save(&mut self) {
+        let today = self.today;
+        self.entries.retain(|e| today - e.day < KEEP_DAYS);
+        storage::write_json(STORAGE_KEY, self);
+    }
+}
+
+fn count(n: usize) -> f64 {
+    f64::from(u32::try_from(n).unwrap_or(u32::MAX))
+}
+
+/// Local calendar day, so "yesterday" matches the household's evening.
+#[allow(clippy::cast_possible_truncation)]
+pub fn today() -> i64 {
+    let now = js_sys::Date::new_0();
+    let local_ms = now.get_time() - now.get_timezone_offset() * 60_000.0;
+    (local_ms / 86_400_000.0).floor() as i64
+}
+
+fn find_item(id: &str) -> Option<&'static Ingredient> {
+    catalog().ingredients.iter().find(|i| i.id.as_str() == id).copied()
+}
+
+fn item_name(id: &str) -> &str {
+    find_item(id).map_or(id, |i| i.name)
+}
+
+fn cuisine_label(id: &str) -> &str {
+    catalog().cuisine_list().into_iter().find(|c| c.id() == id).map_or(id, |c| catalog().label(c))
+}
+
+/// Picks one cooldown out of `Cooldowns` for the settings steppers.
+type CooldownField = fn(&mut Cooldowns) -> &mut i64;
+
+#[component]
+pub fn HistorySettings(history: Signal<History>) -> Element {
+    let h = history.read().clone();
+    let rows: [(&str, i64, CooldownField); 4] = [
+        ("Protein", h.cooldowns.protein, |c| &mut c.protein),
+        ("Starch", h.cooldowns.starch, |c| &mut c.starch),
+        ("Veg", h.cooldowns.veg, |c| &mut c.veg),
+        ("Cuisine", h.cooldowns.cuisine, |c| &mut c.cuisine),
+    ];
+    let mut step = move |field: CooldownField, by: i64| {
+        let mut w = history.write();
+        let v = field(&mut w.cooldowns);
+        *v = (*v + by).clamp(0, 14);
+        w.save();
+    };
+    let recent: Vec<(i64, String)> = h
+        .entries
+        .iter()
+        .rev()
+        .take(7)
+        .map(|e| {
+            let names: Vec<&str> = e.items.iter().map(|i| item_name(i)).collect();
+            (h.today - e.day, format!("{} \u{00B7} {}", cuisine_label(&e.cuisine), names.join(", ")))
+        })
+        .collect();
+
+    rsx! {
+        div { class: "household",
+            span { class: "household__title", "History" }
+            for (label, days, field) in rows {
+                div { class: "household__row",
+                    span { class: "household__label", "{label}" }
+                    button { class: "cuisine-pill", onclick: move |_| step(field, -1), "\u{2212}" }
+                    span { class: "history__days",
+                        if days == 0 { "no cooldown" } else if days == 1 { "1 day" } else { "{days} days" }
+                    }
+                    button { class: "cuisine-pill", onclick: move |_| step(field, 1), "+" }
+                }
+            }
+            if !recent.is_empty() {
+                ul { class: "history__list",
+                    for (ago, text) in recent {
+                        li { class: "history__entry",
+                            span { class: "history__ago",
+                                if ago == 0 { "Today" } else if ago == 1 { "Yesterday" } else { "{ago} days ago" }
+                            }
+                            span { "{text}" }
+                        }
+                    }
+                }
+                button {
+                    class: "veg-toggle-btn",
+                    onclick: move |_| {
+                        let mut w = history.write();
+                        w.entries.clear();
+                        w.save();
+                    },
+                    "Clear history"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TODAY: i64 = 20_000;
+
+    fn entry(days_ago: i64, cuisine: Cuisine, items: &[&str]) -> HistoryEntry {
+        HistoryEntry { day: TODAY - days_ago, cuisine: cuisine.id().to_string(), items: items.iter().map(|s| s.to_string()).collect() }
+    }
+
+    fn history(entries: Vec<HistoryEntry>) -> History {
+        History { entries, today: TODAY, ..History::default() }
+    }
+
+    fn item(id: &str) -> &'static Ingredient {
+        find_item(id).unwrap_or_else(|| panic!("no {id}"))
+    }
+
+    /// A protein cooldown of N days covers the day it was cooked and the
+    /// N - 1 after it; it's back on day N.
+    #[test]
+    fn cooldowns_end_on_day_n() {
+        let steak = item("steak");
+        let n = Cooldowns::default().protein;
+        let cooked = |days_ago| history(vec![entry(days_ago, Cuisine::American, &["steak"])]).on_cooldown(steak);
+        assert!(cooked(0));
+        assert!(cooked(n - 1));
+        assert!(!cooked(n));
+        assert!(!cooked(n + 1));
+    }
+
+    #[test]
+    fn a_zero_cooldown_never_holds_an_item_back() {
+        let h = history(vec![entry(0, Cuisine::American, &["broccoli"])]);
+        assert_eq!(h.cooldowns.veg, 0);
+        assert!(!h.on_cooldown(item("broccoli")));
+        assert!(!h.on_cooldown(item("steak")));
+    }
+
+    #[test]
+    fn fresh_falls_back_to_the_whole_pool() {
+        let h = history(vec![entry(1, Cuisine::American, &["steak", "salmon"])]);
+        assert_eq!(h.fresh(&[item("steak"), item("shrimp")]), vec![item("shrimp")]);
+        assert_eq!(h.fresh(&[item("steak"), item("salmon")]), vec![item("steak"), item("salmon")]);
+    }
+
+    /// Over the last 28 days each cuisine is weighted by how far it trails
+    /// an even split, floored for the ones well ahead.
+    #[test]
+    fn cuisines_that_trail_weigh_more() {
+        use Cuisine::{American, Asian, Bbq, Latin, Mediterranean};
+        let options = [American, Latin, Asian, Mediterranean, Bbq];
+        let mut entries = vec![
+            entry(2, American, &[]),
+            entry(3, American, &[]),
+            entry(4, American, &[]),
+            entry(5, Asian, &[]),
+            entry(DEFICIT_WINDOW_DAYS - 1, Latin, &[]),
+        ];
+        // Outside the window, so it doesn't count
+        entries.push(entry(DEFICIT_WINDOW_DAYS, Asian, &[]));
+        let h = history(entries);
+        // Five recent meals over five cuisines: one each is even
+        assert_eq!(
+            h.cuisine_weights(&options),
+            vec![(American, DEFICIT_FLOOR), (Latin, 1.0), (Asian, 1.0), (Mediterranean, 2.0), (Bbq, 2.0)]
+        );
+    }
+
+    #[test]
+    fn a_cuisine_on_cooldown_sits_out_unless_it_is_the_only_one() {
+        let h = history(vec![entry(0, Cuisine::Latin, &[])]);
+        let weights = h.cuisine_weights(&[Cuisine::American, Cuisine::Latin]);
+        assert_eq!(weights.iter().map(|(c, _)| *c).collect::<Vec<_>>(), vec![Cuisine::American]);
+        assert_eq!(h.cuisine_weights(&[Cuisine::Latin]).len(), 1);
+        assert!(!history(vec![entry(1, Cuisine::Latin, &[])]).cuisine_on_cooldown(Cuisine::Latin));
+    }
+}
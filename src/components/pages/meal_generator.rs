@@ -1,65 +1,118 @@
 #![allow(non_snake_case)]
 
 use dioxus::prelude::*;
-use rand::seq::SliceRandom;
 
-use crate::content::{get_proteins, get_starches, get_vegs, CUISINES};
+use crate::content::{catalog, Archetype, Category, get_proteins, get_starches, get_vegs, Cuisine, IngredientId};
 
-use super::meal_slot::render_slot;
-use super::meal_types::{
-    cascade_from_protein, cuisine_label, describe_meal, in_cuisine,
-    LockState, MealSelection, SlotCtx,
-    SAUCE_SUGGESTIONS,
-};
-use super::generator_logic::generate_slot_options;
+use super::meal_slot::{switch_cuisine, MealSlot};
+use super::meal_types::{describe_meal, fusion_cuisines, GenEnv, LockState, MealSelection, Season, SlotCtx, SlotKind};
+use super::generator_logic::{generate_plate, generate_slot_options};
+use super::content_packs::{ContentPacks, CATALOG_REV};
+use super::preferences::{HouseholdSettings, PREFS};
+use super::history::{HistorySettings, HISTORY};
+use super::pantry::{PantrySettings, ShoppingNote, PANTRY};
+use super::budget::{BudgetSettings, CostLine, BUDGET};
+use super::timing::TimeLine;
+use super::rules::RULES;
+use super::rules_panel::RulesPanel;
+use super::nutrition_panel::NutritionPanel;
+use super::score_panel::ScorePanel;
+use super::provenance::Provenances;
 
 use super::icons::ICON_CHEVRON_DOWN;
 
 #[component]
 pub fn MealGenerator() -> Element {
-    let mut cuisine = use_signal(|| *CUISINES.first().unwrap_or(&"american"));
+    let mut cuisine = use_signal(|| catalog().cuisine_list().first().copied().unwrap_or(Cuisine::American));
     let mut cuisine_lock = use_signal(|| false);
     let mut selection = use_signal(MealSelection::default);
     let locks = use_signal(LockState::default);
     let mut show_veg2 = use_signal(|| false);
     let mut has_generated = use_signal(|| false);
-    let editing = use_signal::<Option<&'static str>>(|| None);
+    let editing = use_signal::<Option<SlotKind>>(|| None);
     let mut cuisine_open = use_signal(|| false);
+    let prefs = PREFS.signal();
+    let mut notice = use_signal::<Option<String>>(|| None);
+    let mut archetype = use_signal::<Option<&'static Archetype>>(|| None);
 
-    let ctx = SlotCtx { locks, selection, editing, cuisine, show_veg2 };
+    let mut seed = use_signal(|| 0u64);
+    let mut seed_input = use_signal(String::new);
+    let mut seed_error = use_signal(|| false);
+    let mut history = HISTORY.signal();
+    let rules = RULES.signal();
+    let mut pantry = PANTRY.signal();
+    let budget = BUDGET.signal();
+    let mut provenance = use_signal(Provenances::default);
 
-    let generate_meal = move |_| {
+    let ctx = SlotCtx {
+        locks,
+        selection,
+        editing,
+        cuisine,
+        show_veg2,
+        prefs,
+        archetype,
+        seed,
+        provenance,
+    };
+    // Re-render against the merged catalog whenever a content pack loads
+    let _catalog_rev = CATALOG_REV();
+
+    // A plate is a function of the seed, the locks and the locked items alone
+    let mut run = move |s: u64| {
         editing.clone().set(None);
-        let current_cuisine = if *cuisine_lock.read() {
-            *cuisine.read()
-        } else {
-            let mut rng = rand::thread_rng();
-            let new_c = *CUISINES.choose(&mut rng).unwrap_or_else(|| CUISINES.first().unwrap_or(&"american"));
-            cuisine.set(new_c);
-            new_c
-        };
-
-        let proteins = get_proteins();
-        let mut rng = rand::thread_rng();
-        let p = if locks.read().protein { selection.read().protein }
-                else { in_cuisine(current_cuisine, &proteins).choose(&mut rng).copied() };
-
-        let mut sel = MealSelection { protein: p, starch: None, veg1: None, veg2: None };
         let lock = *locks.read();
-        if lock.starch { sel.starch = selection.read().starch; }
-        if lock.veg1 { sel.veg1 = selection.read().veg1; }
-        if lock.veg2 { sel.veg2 = selection.read().veg2; }
-        cascade_from_protein(&mut sel, lock, current_cuisine, *show_veg2.read());
-        selection.set(sel);
+        let (meal, records, filtered) = ctx.with_env(|env| {
+            let meal = generate_plate(s, &selection.read(), env, *cuisine_lock.read());
+            let records = Provenances::generated(&meal.selection, GenEnv { cuisine: meal.cuisine, ..env }, meal.archetype, meal.sampled);
+            let filtered = if env.prefs.is_restricted() {
+                Some(env.prefs.describe())
+            } else if env.pantry.requires(Category::Protein) {
+                Some("on-hand".to_string())
+            } else {
+                (!env.rules.excluded.is_empty()).then(|| "rule-abiding".to_string())
+            };
+            (meal, records, filtered)
+        });
+        notice.set(filtered.filter(|_| meal.selection.protein.is_none() && !lock.protein).map(|f| {
+            format!("No {f} protein for {} \u{2014} serving a sides-only plate.", catalog().label(meal.cuisine))
+        }));
+        seed.set(s);
+        seed_input.set(s.to_string());
+        seed_error.set(false);
+        cuisine.set(meal.cuisine);
+        archetype.set(meal.archetype);
+        selection.set(meal.selection);
+        provenance.set(records);
         has_generated.set(true);
     };
+    let generate_meal = move |_| run(rand::random::<u64>());
+    let mut replay_seed = move || match seed_input.read().trim().parse::<u64>() {
+        Ok(s) => run(s),
+        Err(_) => seed_error.set(true),
+    };
 
     let toggle_bg = if *cuisine_lock.read() { "background:var(--accent);" } else { "background:var(--bg-elevated);" };
     let toggle_knob = if *cuisine_lock.read() { "transform:translateX(1.25rem);" } else { "" };
 
     let cur = *cuisine.read();
-    let cur_label = cuisine_label(cur);
+    let cur_label = catalog().label(cur);
     let sel_val = *selection.read();
+    let plate = MealSelection { veg2: if *show_veg2.read() { sel_val.veg2 } else { None }, ..sel_val };
+    let prefs_val = prefs.read().clone();
+    let season = Season::now();
+    let fused = !fusion_cuisines(&plate, cur).is_empty();
+    // The rules' time budget and "done in" combined, as generation reads them
+    let limit = ctx.with_env(|env| env.minutes());
+    let broken = rules.read().parsed().tonight(&history.read()).violations(cur, &plate, archetype());
+    let cooked_today = history.read().cooked_today().is_some_and(|e| {
+        e.cuisine == cur.id()
+            && [plate.protein, plate.starch, plate.veg1, plate.veg2]
+                .into_iter()
+                .flatten()
+                .map(|i| i.id.as_str())
+                .eq(e.items.iter().map(String::as_str))
+    });
 
     // Generate option lists with compatibility metadata
     let protein_alts = generate_slot_options(
@@ -67,6 +120,8 @@ pub fn MealGenerator() -> Element {
         None, // Proteins check against cuisine directly
         cur,
         &[],
+        &prefs_val,
+        season,
     );
 
     let starch_alts = generate_slot_options(
@@ -74,6 +129,8 @@ pub fn MealGenerator() -> Element {
         sel_val.protein,
         cur,
         &[],
+        &prefs_val,
+        season,
     );
 
     let veg1_alts = generate_slot_options(
@@ -81,17 +138,22 @@ pub fn MealGenerator() -> Element {
         sel_val.protein,
         cur,
         &[],
+        &prefs_val,
+        season,
     );
 
-    let veg2_exc: Vec<&str> = sel_val.veg1.map(|v| v.id).into_iter().collect();
+    let veg2_exc: Vec<IngredientId> = sel_val.veg1.map(|v| v.id).into_iter().collect();
     let veg2_alts = generate_slot_options(
         &get_vegs(),
         sel_val.protein,
         cur,
         &veg2_exc,
+        &prefs_val,
+        season,
     );
 
-    let sauces: &[&str] = SAUCE_SUGGESTIONS.iter().find(|(c, _)| *c == cur).map_or(&[], |(_, s)| *s);
+    let sauces = catalog().sauces(cur);
+    let cuisines = catalog().cuisine_list();
     let pills_cls = if *cuisine_open.read() { "cuisine-pills-row cuisine-pills-row--open" } else { "cuisine-pills-row" };
 
     rsx! {
@@ -101,6 +163,7 @@ pub fn MealGenerator() -> Element {
                     "What's For Dinner?"
                 }
                 p { class: "generator-subtitle", "Protein + Starch + Veg" }
+                a { class: "page-link", href: "/plan", "Plan the week \u{2192}" }
             }
 
             // Cuisine selector
@@ -126,10 +189,13 @@ pub fn MealGenerator() -> Element {
                     }
                 }
                 div { class: "{pills_cls}",
-                    for c in CUISINES.iter() {
-                        { let cls = if *cuisine.read() == *c { "cuisine-pill cuisine-pill--active" } else { "cuisine-pill" };
-                          let lbl = cuisine_label(c);
-                          rsx! { button { class: "{cls}", onclick: move |_| { cuisine.set(c); cuisine_open.set(false); }, "{lbl}" } }
+                    for c in cuisines {
+                        { let cls = if *cuisine.read() == c { "cuisine-pill cuisine-pill--active" } else { "cuisine-pill" };
+                          let lbl = catalog().label(c);
+                          rsx! { button { class: "{cls}", onclick: move |_| {
+                              cuisine_open.set(false);
+                              switch_cuisine(ctx, c);
+                          }, "{lbl}" } }
                         }
                     }
                 }
@@ -140,15 +206,65 @@ pub fn MealGenerator() -> Element {
                 if *has_generated.read() { "Generate New Meal" } else { "Generate Meal" }
             }
 
+            div { class: "seed-row",
+                label { class: "seed-row__label", r#for: "meal-seed", "Seed" }
+                input {
+                    id: "meal-seed",
+                    class: if seed_error() { "seed-row__input seed-row__input--error" } else { "seed-row__input" },
+                    r#type: "text",
+                    inputmode: "numeric",
+                    placeholder: "Type a seed to replay a meal",
+                    value: "{seed_input}",
+                    oninput: move |e| {
+                        seed_input.set(e.value());
+                        seed_error.set(false);
+                    },
+                    onkeydown: move |e: Event<KeyboardData>| {
+                        if e.key() == Key::Enter {
+                            replay_seed();
+                        }
+                    },
+                }
+                button { class: "btn seed-row__btn", onclick: move |_| replay_seed(), "Replay" }
+            }
+            if seed_error() {
+                p { class: "seed-row__error", "A seed is a whole number from 0 to {u64::MAX}." }
+            }
+
+            if let Some(msg) = notice.read().as_ref() {
+                p { class: "generator-notice", "{msg}" }
+            }
+
             if *has_generated.read() {
-                if let Some(desc) = describe_meal(&selection.read()) {
+                if !broken.is_empty() {
+                    ul { class: "rule-notes",
+                        for b in broken {
+                            li { "{b}" }
+                        }
+                    }
+                }
+                if archetype().is_some() || fused {
+                    div { class: "archetype-row",
+                        if let Some(a) = archetype() {
+                            span { class: "archetype-badge", "{a.label}" }
+                        }
+                        if fused {
+                            span { class: "archetype-badge archetype-badge--fusion", "Fusion" }
+                        }
+                    }
+                }
+                if let Some(desc) = describe_meal(&selection.read(), cur, seed()) {
                     p { key: "{desc}", class: "meal-desc", "{desc}" }
+                    div { class: "meal-facts",
+                        TimeLine { selection: plate, archetype: archetype(), limit }
+                        CostLine { budget, selection: plate }
+                    }
                 }
-                {render_slot("Protein", "P", "protein", 0, protein_alts, ctx)}
-                {render_slot("Starch", "S", "starch", 1, starch_alts, ctx)}
-                {render_slot("Vegetable", "V", "veg1", 2, veg1_alts, ctx)}
+                MealSlot { slot: SlotKind::Protein, alternatives: protein_alts, ctx }
+                MealSlot { slot: SlotKind::Starch, alternatives: starch_alts, ctx }
+                MealSlot { slot: SlotKind::Veg1, alternatives: veg1_alts, ctx }
                 if *show_veg2.read() {
-                    {render_slot("Extra Veg", "V₂", "veg2", 3, veg2_alts, ctx)}
+                    MealSlot { slot: SlotKind::Veg2, alternatives: veg2_alts, ctx }
                     button { class: "veg-toggle-btn",
                         onclick: move |_| show_veg2.set(false), "Remove Extra Veg"
                     }
@@ -157,6 +273,23 @@ pub fn MealGenerator() -> Element {
                         onclick: move |_| show_veg2.set(true), "+ Extra Veg"
                     }
                 }
+                ShoppingNote { pantry, selection: plate }
+                button {
+                    class: if cooked_today { "btn cook-btn cook-btn--done" } else { "btn cook-btn" },
+                    disabled: cooked_today || plate.protein.is_none(),
+                    onclick: move |_| {
+                        let mut h = history.write();
+                        h.record(cur, &plate);
+                        h.save();
+                        // Swapping tonight's entry puts the last plate's stock back
+                        let mut p = pantry.write();
+                        p.cook(&plate);
+                        p.save();
+                    },
+                    if cooked_today { "Logged for tonight" } else { "We're cooking this" }
+                }
+                ScorePanel { selection: plate, cuisine: cur, archetype: archetype() }
+                NutritionPanel { selection: plate }
                 if !sauces.is_empty() {
                     div { class: "seasoning",
                         span { class: "seasoning__title", "Seasoning · {cur_label}" }
@@ -168,6 +301,13 @@ pub fn MealGenerator() -> Element {
                     }
                 }
             }
+
+            HouseholdSettings { prefs }
+            PantrySettings { pantry }
+            BudgetSettings { budget }
+            HistorySettings { history }
+            RulesPanel { rules, history }
+            ContentPacks {}
         }
     }
 }
@@ -1,39 +1,48 @@
 #![allow(non_snake_case)]
 
 use dioxus::prelude::*;
-use rand::seq::SliceRandom;
 
-use crate::content::Ingredient;
+use crate::content::{catalog, Cuisine, Ingredient};
 
+use super::generator_logic::{derived_rng, pick_protein};
+use super::quality_engine::{cascade_sampled, reroll_side};
 use super::meal_types::{
-    cascade_from_protein, get_item, in_cuisine, is_locked,
-    pairs_with_protein, SlotCtx, SlotOption,
+    clear_unlocked_sides, cuisine_conflicts, cuisines_for_locked, get_item, is_locked, refit_archetype, set_item,
+    toggle_lock, SlotCtx, SlotKind, SlotOption,
 };
+use super::preferences::{update, Frequency, Preferences};
+use super::provenance::{Origin, Provenance, WhyPanel};
 
 use super::icons::{ICON_LOCK, ICON_UNLOCK, ICON_REROLL, ICON_CHEVRON_DOWN, ICON_REMOVE};
 
-pub fn render_slot(
-    label: &str,
-    short_label: &str,
-    field: &'static str,
-    index: u32,
-    alternatives: Vec<SlotOption>,
-    mut ctx: SlotCtx,
-) -> Element {
+/// One plate position's card. A component of its own so each slot keeps
+/// its reroll count and "why this?" state as the extra veg comes and goes.
+#[component]
+pub fn MealSlot(slot: SlotKind, alternatives: Vec<SlotOption>, ctx: SlotCtx) -> Element {
+    let mut ctx = ctx;
     let mut reroll_count = use_signal(|| 0u32);
+    let why_open = use_signal(|| false);
 
+    let label = slot.label();
+    let short_label = slot.short_label();
     let sel = *ctx.selection.read();
-    let item = get_item(&sel, field);
-    let locked = is_locked(*ctx.locks.read(), field);
-    let is_editing = *ctx.editing.read() == Some(field);
+    let item = get_item(&sel, slot);
+    let locked = is_locked(*ctx.locks.read(), slot);
+    let is_editing = *ctx.editing.read() == Some(slot);
+    let allergen_badge = item.and_then(|i| ctx.prefs.read().allergen_badge(i));
+    let nudge = item.and_then(|i| ctx.prefs.read().frequency(i.id));
 
-    let card_class = if locked { "meal-slot meal-slot--locked" } else { "meal-slot" };
+    let conflict = cuisine_conflicts(&sel, *ctx.locks.read(), *ctx.cuisine.read()).contains(&slot);
+    let card_class = match (locked, conflict) {
+        (true, true) => "meal-slot meal-slot--locked meal-slot--conflict",
+        (true, false) => "meal-slot meal-slot--locked",
+        _ => "meal-slot",
+    };
     let keep_class = if locked { "slot-btn slot-btn--lock slot-btn--locked" } else { "slot-btn slot-btn--lock" };
     let arrow_class = if is_editing { "meal-slot__name-arrow meal-slot__name-arrow--open" } else { "meal-slot__name-arrow" };
     let picker_class = if is_editing { "meal-slot__picker meal-slot__picker--open" } else { "meal-slot__picker" };
-    let anim = format!("animation: slotReveal 0.45s var(--ease-out) {}ms both;", index * 70);
+    let anim = format!("animation: slotReveal 0.45s var(--ease-out) {}ms both;", slot.index() * 70);
     let reroll_cls = if *reroll_count.read() > 0 { "slot-btn slot-btn--rerolling" } else { "slot-btn" };
-    let reroll_key = format!("reroll-{field}-{}", *reroll_count.read());
 
     rsx! {
         div { class: "{card_class}", style: "{anim}",
@@ -48,13 +57,7 @@ pub fn render_slot(
                             onclick: move |evt: Event<MouseData>| {
                                 evt.stop_propagation();
                                 let mut s = *ctx.selection.read();
-                                match field {
-                                    "protein" => s.protein = None,
-                                    "starch" => s.starch = None,
-                                    "veg1" => s.veg1 = None,
-                                    "veg2" => s.veg2 = None,
-                                    _ => {}
-                                }
+                                set_item(&mut s, slot, None);
                                 ctx.selection.set(s);
                                 ctx.editing.set(None);
                             },
@@ -64,25 +67,16 @@ pub fn render_slot(
                     button {
                         class: "{keep_class}",
                         title: if locked { "Unlock" } else { "Keep this" },
-                        onclick: move |_| {
-                            let mut w = ctx.locks.write();
-                            match field {
-                                "protein" => w.protein = !w.protein,
-                                "starch" => w.starch = !w.starch,
-                                "veg1" => w.veg1 = !w.veg1,
-                                "veg2" => w.veg2 = !w.veg2,
-                                _ => {}
-                            }
-                        },
+                        onclick: move |_| toggle_lock(&mut ctx.locks.write(), slot),
                         span { dangerous_inner_html: if locked { ICON_LOCK } else { ICON_UNLOCK } }
                     }
                     button {
-                        key: "{reroll_key}",
+                        key: "reroll-{slot:?}-{reroll_count}",
                         class: "{reroll_cls}",
                         title: "Re-roll",
                         onclick: move |_| {
                             reroll_count += 1;
-                            reroll_field(field, ctx);
+                            reroll_field(slot, ctx, reroll_count());
                         },
                         span { dangerous_inner_html: ICON_REROLL }
                     }
@@ -93,16 +87,23 @@ pub fn render_slot(
                     span {
                         class: "meal-slot__name",
                         onclick: move |_| {
-                            if *ctx.editing.read() == Some(field) {
+                            if *ctx.editing.read() == Some(slot) {
                                 ctx.editing.set(None);
                             } else {
-                                ctx.editing.set(Some(field));
+                                ctx.editing.set(Some(slot));
                             }
                         },
                         "{i.name}"
                         span { class: "{arrow_class}", dangerous_inner_html: ICON_CHEVRON_DOWN }
                     }
-
+                    if let Some(badge) = allergen_badge {
+                        span { class: "meal-slot__badge meal-slot__badge--allergen", "{badge}" }
+                    }
+                    {render_freq(i, nudge, ctx.prefs)}
+                    {render_why(slot, why_open, &ctx)}
+                    if conflict {
+                        {render_conflict(i, slot, ctx)}
+                    }
                 } else {
                     p { class: "meal-slot__empty", "—" }
                 }
@@ -110,7 +111,7 @@ pub fn render_slot(
             div { class: "{picker_class}",
                 div { class: "meal-slot__picker-grid",
                     for alt in alternatives {
-                        {render_option(alt, item, field, ctx)}
+                        {render_option(alt, item, slot, ctx)}
                     }
                 }
             }
@@ -118,105 +119,190 @@ pub fn render_slot(
     }
 }
 
+fn render_why(slot: SlotKind, mut open: Signal<bool>, ctx: &SlotCtx) -> Element {
+    let sel = *ctx.selection.read();
+    let locked = is_locked(*ctx.locks.read(), slot);
+    let why = get_item(&sel, slot)
+        .filter(|_| open())
+        .and_then(|item| ctx.provenance.read().get(slot, item).map(|p| Provenance { locked, ..p.clone() }));
+
+    rsx! {
+        button {
+            class: if open() { "meal-slot__why-btn meal-slot__why-btn--on" } else { "meal-slot__why-btn" },
+            title: "Why this?",
+            onclick: move |_| open.toggle(),
+            "?"
+        }
+        if let Some(why) = why {
+            WhyPanel { why }
+        }
+    }
+}
+
+/// A locked item the plate's cuisine doesn't list, with the two ways out.
+fn render_conflict(i: &'static Ingredient, slot: SlotKind, mut ctx: SlotCtx) -> Element {
+    let cuisine = catalog().label(*ctx.cuisine.read());
+    let fixes = cuisines_for_locked(&ctx.selection.read(), *ctx.locks.read());
+
+    rsx! {
+        div { class: "meal-slot__conflict",
+            span { class: "meal-slot__conflict-text", "{i.name} isn't a {cuisine} item" }
+            button {
+                class: "meal-slot__fix",
+                onclick: move |_| toggle_lock(&mut ctx.locks.write(), slot),
+                "Unlock"
+            }
+            for c in fixes {
+                button {
+                    class: "meal-slot__fix",
+                    onclick: move |_| switch_cuisine(ctx, c),
+                    "Switch to {catalog().label(c)}"
+                }
+            }
+        }
+    }
+}
+
+/// Moves the plate to `c`, keeping the archetype when it still fits.
+pub fn switch_cuisine(mut ctx: SlotCtx, c: Cuisine) {
+    ctx.cuisine.set(c);
+    let mut rng = derived_rng(*ctx.seed.read(), &format!("cuisine-{}", c.id()));
+    let sel = *ctx.selection.read();
+    let arch = refit_archetype(*ctx.archetype.read(), &sel, c, &mut rng);
+    let mut records = ctx.provenance;
+    ctx.with_env(|env| records.write().recuisine(&sel, None, env, arch));
+    ctx.archetype.set(arch);
+}
+
+fn render_freq(i: &'static Ingredient, nudge: Option<Frequency>, prefs: Signal<Preferences>) -> Element {
+    rsx! {
+        span { class: "meal-slot__freq",
+            for f in [Frequency::More, Frequency::Less] {
+                button {
+                    class: if nudge == Some(f) { "meal-slot__freq-btn meal-slot__freq-btn--on" } else { "meal-slot__freq-btn" },
+                    title: "Suggest {i.name} {f.label().to_lowercase()}",
+                    onclick: move |evt: Event<MouseData>| {
+                        evt.stop_propagation();
+                        update(prefs, |p| p.toggle_frequency(i.id, f));
+                    },
+                    "{f.arrow()}"
+                }
+            }
+        }
+    }
+}
+
+/// Class and tooltip for a picker option: the current item, one from
+/// another cuisine, one a household filter excludes, one out of season.
+pub fn option_look(opt: &SlotOption, current: Option<&'static Ingredient>) -> (String, String) {
+    let mut cls = "meal-slot__option".to_string();
+    if current == Some(opt.ingredient) {
+        cls.push_str(" meal-slot__option--active");
+    } else if !opt.is_compatible {
+        cls.push_str(" meal-slot__option--incompatible");
+    }
+    if opt.blocked.is_some() {
+        cls.push_str(" meal-slot__option--filtered");
+    }
+    let mut title = if let Some(reason) = opt.blocked {
+        reason.to_string()
+    } else if opt.is_compatible {
+        String::new()
+    } else if let Some(c) = opt.target_cuisine {
+        format!("Switch to {}", catalog().label(c))
+    } else {
+        "Incompatible".to_string()
+    };
+    if opt.out_of_season {
+        cls.push_str(" meal-slot__option--off-season");
+        title = if title.is_empty() { "Out of season".to_string() } else { format!("Out of season \u{00B7} {title}") };
+    }
+    (cls, title)
+}
+
 fn render_option(
     opt: SlotOption,
     current: Option<&'static Ingredient>,
-    field: &'static str,
+    slot: SlotKind,
     mut ctx: SlotCtx,
 ) -> Element {
     let ingredient = opt.ingredient;
-    let is_compat = opt.is_compatible;
     let target_cuisine = opt.target_cuisine;
     let clear_prot = opt.clear_protein;
-
-    let mut cls = "meal-slot__option".to_string();
-    if current == Some(ingredient) {
-        cls.push_str(" meal-slot__option--active");
-    } else if !is_compat {
-        cls.push_str(" meal-slot__option--incompatible");
-    }
+    let (cls, title) = option_look(&opt, current);
 
     rsx! {
         button {
             class: "{cls}",
-            title: if is_compat {
-                String::new()
-            } else if let Some(c) = target_cuisine {
-                format!("Switch to {c}")
-            } else {
-                "Incompatible".to_string()
-            },
+            title: "{title}",
             onclick: move |_| {
                 let mut s = *ctx.selection.read();
-                let lock = *ctx.locks.read();
-                let sv2 = *ctx.show_veg2.read();
-                let mut c_val = *ctx.cuisine.read();
+                let prev_arch = *ctx.archetype.read();
+                let mut rng = derived_rng(*ctx.seed.read(), &format!("pick-{slot:?}-{}", ingredient.id.as_str()));
 
                 // Handle incompatibility actions
+                let moved = target_cuisine.is_some_and(|t| t != *ctx.cuisine.read());
                 if let Some(target) = target_cuisine {
                     ctx.cuisine.set(target);
-                    c_val = target;
                     if clear_prot {
                         s.protein = None;
                     }
                 }
 
-                match field {
-                    "protein" => { 
-                        s.protein = Some(ingredient); 
-                        if !locked_conflicts(lock, field) {
-                             cascade_from_protein(&mut s, lock, c_val, sv2); 
+                set_item(&mut s, slot, Some(ingredient));
+                let mut records = ctx.provenance;
+                let arch = ctx.with_env(|env| {
+                    let mut records = records.write();
+                    if slot == SlotKind::Protein {
+                        let (arch, sampled) = cascade_sampled(&mut s, env, &mut rng);
+                        records.set(&s, slot, Origin::Picked, env, arch);
+                        records.cascade(&s, env, arch, sampled);
+                        arch
+                    } else {
+                        let arch = refit_archetype(prev_arch, &s, env.cuisine, &mut rng);
+                        records.set(&s, slot, Origin::Picked, env, arch);
+                        if moved {
+                            records.recuisine(&s, Some(slot), env, arch);
                         }
+                        arch
                     }
-                    "starch" => { s.starch = Some(ingredient); }
-                    "veg1" => { s.veg1 = Some(ingredient); }
-                    "veg2" => { s.veg2 = Some(ingredient); }
-                    _ => {}
-                }
+                });
+                ctx.archetype.set(arch);
                 ctx.selection.set(s);
                 ctx.editing.set(None);
             },
             "{ingredient.name}"
+            if opt.out_of_season {
+                span { class: "meal-slot__option-season", "out of season" }
+            }
         }
     }
 }
 
-fn locked_conflicts(_lock: super::meal_types::LockState, _field: &str) -> bool {
-    // Ideally check if cascade would overwrite locked items?
-    // cascade_from_protein respects locks.
-    false
-}
-
-fn reroll_field(field: &'static str, mut ctx: SlotCtx) {
-    let c = *ctx.cuisine.read();
-    let lock = *ctx.locks.read();
-    let sv2 = *ctx.show_veg2.read();
+/// Rerolls are seeded from the plate's seed plus the slot and how many
+/// times it has been rerolled, so a replayed seed rerolls the same way.
+fn reroll_field(slot: SlotKind, mut ctx: SlotCtx, n: u32) {
     let mut s = *ctx.selection.read();
-    let mut rng = rand::thread_rng();
+    let arch = *ctx.archetype.read();
+    let mut rng = derived_rng(*ctx.seed.read(), &format!("reroll-{slot:?}-{n}"));
 
-    match field {
-        "protein" => {
-            let list = crate::content::get_proteins();
-            s.protein = in_cuisine(c, &list).choose(&mut rng).copied();
-            cascade_from_protein(&mut s, lock, c, sv2);
-        }
-        "starch" => {
-            let list = crate::content::get_starches();
-            s.starch = pairs_with_protein(s.protein, c, &list, &[])
-                .choose(&mut rng).copied();
+    let mut records = ctx.provenance;
+    let new_arch = ctx.with_env(|env| {
+        let mut records = records.write();
+        if slot == SlotKind::Protein {
+            clear_unlocked_sides(&mut s, env.lock);
+            s.protein = pick_protein(&s, env, &mut rng);
+            let (new_arch, sampled) = cascade_sampled(&mut s, env, &mut rng);
+            records.set(&s, slot, Origin::Rerolled, env, new_arch);
+            records.cascade(&s, env, new_arch, sampled);
+            new_arch
+        } else {
+            let pick = reroll_side(&s, slot, env, arch, &mut rng);
+            set_item(&mut s, slot, pick);
+            records.set(&s, slot, Origin::Rerolled, env, arch);
+            arch
         }
-        "veg1" => {
-            let list = crate::content::get_vegs();
-            s.veg1 = pairs_with_protein(s.protein, c, &list, &[])
-                .choose(&mut rng).copied();
-        }
-        "veg2" => {
-            let list = crate::content::get_vegs();
-            let exc: Vec<&str> = s.veg1.map(|v| v.id).into_iter().collect();
-            s.veg2 = pairs_with_protein(s.protein, c, &list, &exc)
-                .choose(&mut rng).copied();
-        }
-        _ => {}
-    }
+    });
+    ctx.archetype.set(new_arch);
     ctx.selection.set(s);
 }
@@ -2,16 +2,62 @@
 
 use dioxus::prelude::*;
 use rand::seq::SliceRandom;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
 
-use crate::content::{Ingredient, CUISINE_LABELS, get_starches, get_vegs};
+use crate::content::{
+    archetypes_for, catalog, Archetype, Cuisine, Ingredient, IngredientId, Minutes, Nutrition, get_proteins,
+    get_starches, get_vegs,
+};
 
-pub static SAUCE_SUGGESTIONS: &[(&str, &[&str])] = &[
-    ("american", &["garlic butter", "salt & pepper", "herb seasoning", "ranch", "gravy", "mustard"]),
-    ("latin", &["taco seasoning", "chimichurri", "salsa & lime", "cumin & chili", "adobo", "mojo", "hot sauce"]),
-    ("asian", &["teriyaki", "soy & ginger", "sesame glaze", "sweet chili", "hoisin", "ponzu", "sriracha"]),
-    ("mediterranean", &["lemon herb", "dill & lemon", "olive oil & oregano", "balsamic", "tzatziki", "harissa", "pesto"]),
-    ("bbq", &["BBQ rub", "smoked paprika", "honey mustard glaze", "cajun seasoning", "dry rub", "Carolina vinegar", "Alabama white sauce"]),
-];
+use super::history::{History, HISTORY};
+use super::pantry::{Pantry, PANTRY};
+use super::budget::{Budget, BUDGET};
+use super::provenance::Provenances;
+use super::timing::{easiest, minutes_over, quick_items};
+use super::rules::{MealRules, RuleSet, RULES};
+use super::preferences::{Preferences, PREFS};
+
+/// One of the four plate positions. Labels and card order live here so the
+/// generator page and the slot cards stay in sync.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SlotKind {
+    Protein,
+    Starch,
+    Veg1,
+    Veg2,
+}
+
+impl SlotKind {
+    pub const ALL: [SlotKind; 4] = [SlotKind::Protein, SlotKind::Starch, SlotKind::Veg1, SlotKind::Veg2];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            SlotKind::Protein => "Protein",
+            SlotKind::Starch => "Starch",
+            SlotKind::Veg1 => "Vegetable",
+            SlotKind::Veg2 => "Extra Veg",
+        }
+    }
+
+    pub const fn short_label(self) -> &'static str {
+        match self {
+            SlotKind::Protein => "P",
+            SlotKind::Starch => "S",
+            SlotKind::Veg1 => "V",
+            SlotKind::Veg2 => "V₂",
+        }
+    }
+
+    pub const fn index(self) -> u32 {
+        match self {
+            SlotKind::Protein => 0,
+            SlotKind::Starch => 1,
+            SlotKind::Veg1 => 2,
+            SlotKind::Veg2 => 3,
+        }
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, Default)]
 pub struct MealSelection {
@@ -25,8 +71,11 @@ pub struct MealSelection {
 pub struct SlotOption {
     pub ingredient: &'static Ingredient,
     pub is_compatible: bool,
-    pub target_cuisine: Option<&'static str>,
+    pub target_cuisine: Option<Cuisine>,
     pub clear_protein: bool,
+    /// Set when a household filter excludes the item; it stays pickable.
+    pub blocked: Option<&'static str>,
+    pub out_of_season: bool,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Default)]
@@ -37,22 +86,101 @@ pub struct LockState {
     pub veg2: bool,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq)]
 pub struct SlotCtx {
     pub locks: Signal<LockState>,
     pub selection: Signal<MealSelection>,
-    pub editing: Signal<Option<&'static str>>,
-    pub cuisine: Signal<&'static str>,
+    pub editing: Signal<Option<SlotKind>>,
+    pub cuisine: Signal<Cuisine>,
     pub show_veg2: Signal<bool>,
+    pub prefs: Signal<Preferences>,
+    pub archetype: Signal<Option<&'static Archetype>>,
+    /// Seed of the last generated plate; rerolls and picks derive from it.
+    pub seed: Signal<u64>,
+    /// What each slot was filled with and why, for its "why this?" panel.
+    pub provenance: Signal<Provenances>,
+}
+
+impl SlotCtx {
+    /// Runs `f` with the page's current settings as a `GenEnv`.
+    pub fn with_env<T>(&self, f: impl FnOnce(GenEnv) -> T) -> T {
+        let house = Household::now();
+        let rules = house.rules.tonight(&house.history);
+        f(GenEnv {
+            cuisine: *self.cuisine.read(),
+            lock: *self.locks.read(),
+            has_veg2: *self.show_veg2.read(),
+            prefs: &house.prefs,
+            history: &house.history,
+            rules: &rules,
+            pantry: &house.pantry,
+            budget: &house.budget,
+            season: house.season,
+        })
+    }
+}
+
+/// The saved household settings, read once per generation step. Both the
+/// plate and the week planner build their environments from it.
+pub struct Household {
+    pub prefs: Preferences,
+    pub history: History,
+    pub rules: RuleSet,
+    pub pantry: Pantry,
+    pub budget: Budget,
+    pub season: Season,
 }
 
-pub fn in_cuisine(c: &str, list: &[&'static Ingredient]) -> Vec<&'static Ingredient> {
+impl Household {
+    pub fn now() -> Self {
+        Household {
+            prefs: PREFS.read().clone(),
+            history: HISTORY.read().clone(),
+            rules: RULES.read().parsed(),
+            pantry: PANTRY.read().clone(),
+            budget: BUDGET.read().clone(),
+            season: Season::now(),
+        }
+    }
+}
+
+pub fn in_cuisine(c: Cuisine, list: &[&'static Ingredient]) -> Vec<&'static Ingredient> {
     list.iter()
         .filter(|i| i.cuisines.iter().any(|(cui, _)| *cui == c))
         .copied()
         .collect()
 }
 
+/// Which way a side's pairing with the protein runs in a cuisine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pairing {
+    /// The protein lists the side.
+    Forward,
+    /// The side lists the protein.
+    Reverse,
+    Both,
+    Unpaired,
+}
+
+impl Pairing {
+    pub fn of(protein: &Ingredient, i: &Ingredient, c: Cuisine) -> Pairing {
+        let lists = |x: &Ingredient, y: &Ingredient| {
+            x.cuisines.iter().find(|(cui, _)| *cui == c).is_some_and(|(_, pairs)| pairs.contains(&y.id))
+        };
+        match (lists(protein, i), lists(i, protein)) {
+            (true, true) => Pairing::Both,
+            (true, false) => Pairing::Forward,
+            (false, true) => Pairing::Reverse,
+            (false, false) => Pairing::Unpaired,
+        }
+    }
+
+    /// True when either item lists the other.
+    pub fn paired(self) -> bool {
+        self != Pairing::Unpaired
+    }
+}
+
 /// Bidirectional pairing: returns items where EITHER the protein
 /// lists the item as a pair OR the item lists the protein as a pair,
 /// both within the given cuisine. This ensures broccoli shows up for
@@ -60,16 +188,16 @@ pub fn in_cuisine(c: &str, list: &[&'static Ingredient]) -> Vec<&'static Ingredi
 /// broccoli's list mentions steak.
 pub fn pairs_with_protein(
     p: Option<&'static Ingredient>,
-    c: &str,
+    c: Cuisine,
     list: &[&'static Ingredient],
-    exclude: &[&str],
+    exclude: &[IngredientId],
 ) -> Vec<&'static Ingredient> {
     let Some(protein) = p else {
         return in_cuisine(c, list);
     };
 
     // Forward: ids the protein says it pairs with
-    let forward: &[&str] = protein
+    let forward: &[IngredientId] = protein
         .cuisines
         .iter()
         .find(|(cui, _)| *cui == c)
@@ -94,74 +222,625 @@ pub fn pairs_with_protein(
         .collect()
 }
 
-pub fn get_item(sel: &MealSelection, field: &str) -> Option<&'static Ingredient> {
-    match field {
-        "protein" => sel.protein,
-        "starch" => sel.starch,
-        "veg1" => sel.veg1,
-        "veg2" => sel.veg2,
-        _ => None,
+/// The cuisine a side is cooked in next to `p` on a plate in `c`: `c` when
+/// they pair there, otherwise the first other cuisine where they do.
+pub fn side_cuisine(p: &Ingredient, side: &Ingredient, c: Cuisine) -> Option<Cuisine> {
+    let pairs = |k: Cuisine| Pairing::of(p, side, k).paired();
+    if pairs(c) {
+        return Some(c);
+    }
+    catalog().cuisine_list().into_iter().find(|&k| pairs(k))
+}
+
+/// The cuisines other than `c` that the sides of a fusion plate come from.
+pub fn fusion_cuisines(sel: &MealSelection, c: Cuisine) -> Vec<Cuisine> {
+    let Some(p) = sel.protein else { return Vec::new() };
+    let mut out = Vec::new();
+    for side in [sel.starch, sel.veg1, sel.veg2].into_iter().flatten() {
+        if let Some(k) = side_cuisine(p, side, c).filter(|&k| k != c && !out.contains(&k)) {
+            out.push(k);
+        }
+    }
+    out
+}
+
+/// How many of the sides on `sel` pair, either way, with `p` in `c`.
+pub fn sides_paired(p: &Ingredient, sel: &MealSelection, c: Cuisine) -> usize {
+    let sides = [sel.starch, sel.veg1, sel.veg2].into_iter().flatten();
+    sides.filter(|s| Pairing::of(p, s, c).paired()).count()
+}
+
+/// The reverse cascade: proteins from `list` in `c` that pair with as many
+/// of the sides already on `sel` as any protein there does.
+pub fn pairs_with_sides(sel: &MealSelection, c: Cuisine, list: &[&'static Ingredient]) -> Vec<&'static Ingredient> {
+    let scored: Vec<_> = in_cuisine(c, list).into_iter().map(|p| (sides_paired(p, sel, c), p)).collect();
+    let best = scored.iter().map(|(n, _)| *n).max();
+    scored.into_iter().filter(|(n, _)| Some(*n) == best).map(|(_, p)| p).collect()
+}
+
+/// `pool` narrowed to the items that pair with every locked side of `sel`
+/// other than `slot`, or all of it when none does.
+fn pairs_with_locked(
+    sel: &MealSelection,
+    slot: SlotKind,
+    env: GenEnv,
+    pool: Vec<&'static Ingredient>,
+) -> Vec<&'static Ingredient> {
+    let locked: Vec<&Ingredient> = [SlotKind::Starch, SlotKind::Veg1, SlotKind::Veg2]
+        .into_iter()
+        .filter(|&s| s != slot && is_locked(env.lock, s))
+        .filter_map(|s| get_item(sel, s))
+        .collect();
+    let paired: Vec<_> = pool
+        .iter()
+        .copied()
+        .filter(|i| locked.iter().all(|l| Pairing::of(l, i, env.cuisine).paired()))
+        .collect();
+    if paired.is_empty() { pool } else { paired }
+}
+
+pub fn get_item(sel: &MealSelection, slot: SlotKind) -> Option<&'static Ingredient> {
+    match slot {
+        SlotKind::Protein => sel.protein,
+        SlotKind::Starch => sel.starch,
+        SlotKind::Veg1 => sel.veg1,
+        SlotKind::Veg2 => sel.veg2,
+    }
+}
+
+pub fn set_item(sel: &mut MealSelection, slot: SlotKind, item: Option<&'static Ingredient>) {
+    match slot {
+        SlotKind::Protein => sel.protein = item,
+        SlotKind::Starch => sel.starch = item,
+        SlotKind::Veg1 => sel.veg1 = item,
+        SlotKind::Veg2 => sel.veg2 = item,
+    }
+}
+
+pub fn is_locked(locks: LockState, slot: SlotKind) -> bool {
+    match slot {
+        SlotKind::Protein => locks.protein,
+        SlotKind::Starch => locks.starch,
+        SlotKind::Veg1 => locks.veg1,
+        SlotKind::Veg2 => locks.veg2,
+    }
+}
+
+/// Locked slots whose item isn't listed under `c`.
+pub fn cuisine_conflicts(sel: &MealSelection, lock: LockState, c: Cuisine) -> Vec<SlotKind> {
+    SlotKind::ALL
+        .into_iter()
+        .filter(|&s| is_locked(lock, s) && get_item(sel, s).is_some_and(|i| !i.cuisines.iter().any(|(ic, _)| *ic == c)))
+        .collect()
+}
+
+/// Cuisines that list every locked item on `sel`.
+pub fn cuisines_for_locked(sel: &MealSelection, lock: LockState) -> Vec<Cuisine> {
+    catalog().cuisine_list().into_iter().filter(|&c| cuisine_conflicts(sel, lock, c).is_empty()).collect()
+}
+
+pub fn toggle_lock(locks: &mut LockState, slot: SlotKind) {
+    match slot {
+        SlotKind::Protein => locks.protein = !locks.protein,
+        SlotKind::Starch => locks.starch = !locks.starch,
+        SlotKind::Veg1 => locks.veg1 = !locks.veg1,
+        SlotKind::Veg2 => locks.veg2 = !locks.veg2,
     }
 }
 
-pub fn is_locked(locks: LockState, field: &str) -> bool {
-    match field {
-        "protein" => locks.protein,
-        "starch" => locks.starch,
-        "veg1" => locks.veg1,
-        "veg2" => locks.veg2,
-        _ => false,
+/// Empties every unlocked side so stale picks don't constrain a new plate.
+pub fn clear_unlocked_sides(sel: &mut MealSelection, lock: LockState) {
+    for slot in [SlotKind::Starch, SlotKind::Veg1, SlotKind::Veg2] {
+        if !is_locked(lock, slot) {
+            set_item(sel, slot, None);
+        }
     }
 }
 
-pub fn cascade_from_protein(
-    sel: &mut MealSelection,
-    lock: LockState,
-    cuisine: &str,
-    has_veg2: bool,
-) {
-    let starches = get_starches();
-    let vegs = get_vegs();
-    let mut rng = rand::thread_rng();
+/// Pairing candidates for `slot` that don't repeat or clash with anything
+/// else on the plate (exclusion groups, redundancy tags, signal conflicts),
+/// narrowed to `arch`'s hard constraints when one is given. This is Tier 1;
+/// preferences are scored in `quality_engine`.
+pub fn slot_candidates(
+    sel: &MealSelection,
+    slot: SlotKind,
+    cuisine: Cuisine,
+    list: &[&'static Ingredient],
+    arch: Option<&Archetype>,
+) -> Vec<&'static Ingredient> {
+    let others: Vec<IngredientId> = SlotKind::ALL
+        .into_iter()
+        .filter(|&s| s != slot)
+        .filter_map(|s| get_item(sel, s))
+        .map(|i| i.id)
+        .collect();
+    let cat = catalog();
+    let pool = if slot == SlotKind::Protein {
+        in_cuisine(cuisine, list)
+    } else {
+        pairs_with_protein(sel.protein, cuisine, list, &others)
+    };
+    let pool: Vec<_> = pool
+        .into_iter()
+        .filter(|i| !others.iter().any(|&o| cat.clashes(i.id, o)))
+        .collect();
+    let Some(a) = arch else { return pool };
+
+    pool.into_iter().filter(|i| fits_archetype(a, sel, slot, i)).collect()
+}
 
-    if !lock.starch {
-        sel.starch = pairs_with_protein(sel.protein, cuisine, &starches, &[])
-            .choose(&mut rng).copied();
+/// Sides from `list` that pair with the protein only in a cuisine other
+/// than `cuisine`, for fusion plates. Clashes and `arch` still apply.
+pub fn cross_candidates(
+    sel: &MealSelection,
+    slot: SlotKind,
+    cuisine: Cuisine,
+    list: &[&'static Ingredient],
+    arch: Option<&Archetype>,
+) -> Vec<&'static Ingredient> {
+    if slot == SlotKind::Protein || sel.protein.is_none() {
+        return Vec::new();
     }
-    if !lock.veg1 {
-        sel.veg1 = pairs_with_protein(sel.protein, cuisine, &vegs, &[])
-            .choose(&mut rng).copied();
+    let home = slot_candidates(sel, slot, cuisine, list, arch);
+    let mut out: Vec<&'static Ingredient> = Vec::new();
+    for c in catalog().cuisine_list().into_iter().filter(|&c| c != cuisine) {
+        for i in slot_candidates(sel, slot, c, list, arch) {
+            if !home.iter().chain(&out).any(|h| h.id == i.id) {
+                out.push(i);
+            }
+        }
     }
-    if !lock.veg2 && has_veg2 {
-        let exc: Vec<&str> = sel.veg1.map(|v| v.id).into_iter().collect();
-        sel.veg2 = pairs_with_protein(sel.protein, cuisine, &vegs, &exc)
-            .choose(&mut rng).copied();
+    out
+}
+
+fn is_cold(i: &Ingredient) -> bool {
+    catalog().meta(i.id).is_some_and(|m| m.has_tag("cold_side"))
+}
+
+/// Whether `i` may sit in `slot` under the archetype's hard constraints.
+pub fn fits_archetype(a: &Archetype, sel: &MealSelection, slot: SlotKind, i: &Ingredient) -> bool {
+    let m = catalog().meta(i.id);
+    match slot {
+        SlotKind::Protein => a.allows_protein(m),
+        SlotKind::Starch => a.allows_starch(m),
+        SlotKind::Veg1 | SlotKind::Veg2 => {
+            let other = if slot == SlotKind::Veg1 { sel.veg2 } else { sel.veg1 };
+            let cold = usize::from(is_cold(i)) + usize::from(other.is_some_and(is_cold));
+            a.allows_veg(m) && cold <= usize::from(a.constraints.max_cold_sides)
+        }
     }
 }
 
-pub fn cuisine_label(c: &str) -> &'static str {
-    CUISINE_LABELS
-        .iter()
-        .find(|(k, _)| *k == c)
-        .map_or("Unknown", |(_, v)| *v)
+fn archetype_accepts(a: &Archetype, sel: &MealSelection) -> bool {
+    SlotKind::ALL
+        .into_iter()
+        .all(|s| get_item(sel, s).is_none_or(|i| fits_archetype(a, sel, s, i)))
+}
+
+/// Time and money set aside for sides not yet on the plate.
+#[derive(Clone, Debug, Default)]
+pub struct Reserve {
+    pub minutes: Vec<Minutes>,
+    pub cents: u32,
+}
+
+/// The side pools `reserve` draws from, fetched once per decision.
+struct SidePools {
+    starches: Vec<&'static Ingredient>,
+    vegs: Vec<&'static Ingredient>,
+}
+
+/// In-season items are drawn this many times as often as out-of-season
+/// ones when the household hasn't asked for in season only.
+pub const IN_SEASON_WEIGHT: f64 = 2.0;
+
+/// The month generation runs in, 1–12; while it's 0 (not known) every
+/// item counts as in season.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct Season {
+    pub month: u32,
+}
+
+impl Season {
+    /// The local calendar month.
+    pub fn now() -> Self {
+        Season { month: js_sys::Date::new_0().get_month() + 1 }
+    }
+
+    pub fn in_season(self, i: &Ingredient) -> bool {
+        catalog().in_season(i.id, self.month)
+    }
+
+    /// Draw weight for `i`: `IN_SEASON_WEIGHT` in season, 1 otherwise or
+    /// while the month isn't known.
+    pub fn weight(self, i: &Ingredient) -> f64 {
+        if self.month != 0 && self.in_season(i) { IN_SEASON_WEIGHT } else { 1.0 }
+    }
+}
+
+/// Everything a generation step reads besides the plate and the RNG.
+#[derive(Clone, Copy)]
+pub struct GenEnv<'a> {
+    pub cuisine: Cuisine,
+    pub lock: LockState,
+    pub has_veg2: bool,
+    pub prefs: &'a Preferences,
+    pub history: &'a History,
+    pub rules: &'a MealRules,
+    pub pantry: &'a Pantry,
+    pub budget: &'a Budget,
+    pub season: Season,
+}
+
+impl GenEnv<'_> {
+    /// The "done in" limit: the household's or tonight's rule, whichever
+    /// is tighter.
+    pub fn minutes(&self) -> Option<u32> {
+        [self.prefs.done_in, self.rules.minutes.as_ref().map(|(m, _)| *m)].into_iter().flatten().min()
+    }
+
+    /// The ingredients for `slot`'s category that the household filters,
+    /// rules, pantry mode and season setting allow, and that finish within
+    /// the time limit.
+    /// Proteins also have to leave time and budget, in at least one of
+    /// their cuisines, for the easiest sides that pair with them, unless
+    /// none does.
+    pub fn pool(&self, slot: SlotKind) -> Vec<&'static Ingredient> {
+        let list = match slot {
+            SlotKind::Protein => get_proteins(),
+            SlotKind::Starch => get_starches(),
+            SlotKind::Veg1 | SlotKind::Veg2 => get_vegs(),
+        };
+        let list = quick_items(self.pantry.allowed(self.rules.allowed(self.prefs.allowed(list))), self.minutes());
+        let list: Vec<_> = if self.prefs.in_season_only {
+            list.into_iter().filter(|i| self.season.in_season(i)).collect()
+        } else {
+            list
+        };
+        if slot != SlotKind::Protein || !self.limited() {
+            return list;
+        }
+        let sides = self.side_pools();
+        let fits = |p: &&'static Ingredient| {
+            let sel = MealSelection { protein: Some(p), ..MealSelection::default() };
+            p.cuisines.iter().any(|&(c, _)| self.overrun(&sel, None, &self.reserve(&sel, c, None, None, &sides)) == (0, 0))
+        };
+        let ok: Vec<_> = list.iter().copied().filter(fits).collect();
+        if ok.is_empty() { list } else { ok }
+    }
+
+    /// `slot_candidates` in the plate's cuisine, plus cross-cuisine pairings
+    /// when the household wants fusion.
+    pub fn candidates(
+        &self,
+        sel: &MealSelection,
+        slot: SlotKind,
+        list: &[&'static Ingredient],
+        arch: Option<&Archetype>,
+    ) -> Vec<&'static Ingredient> {
+        let mut pool = slot_candidates(sel, slot, self.cuisine, list, arch);
+        if self.prefs.fusion {
+            pool.extend(cross_candidates(sel, slot, self.cuisine, list, arch));
+        }
+        pool
+    }
+
+    fn limited(&self) -> bool {
+        self.budget.max_cents.is_some() || self.minutes().is_some()
+    }
+
+    fn side_pools(&self) -> SidePools {
+        SidePools { starches: self.pool(SlotKind::Starch), vegs: self.pool(SlotKind::Veg1) }
+    }
+
+    /// Least hands-on time and lowest price for each empty side of `sel`
+    /// other than `skip`: what the rest of the plate will need at minimum.
+    fn reserve(
+        &self,
+        sel: &MealSelection,
+        cuisine: Cuisine,
+        arch: Option<&Archetype>,
+        skip: Option<SlotKind>,
+        sides: &SidePools,
+    ) -> Reserve {
+        let mut out = Reserve::default();
+        for s in [SlotKind::Starch, SlotKind::Veg1, SlotKind::Veg2] {
+            if Some(s) == skip || get_item(sel, s).is_some() || (s == SlotKind::Veg2 && !self.has_veg2) {
+                continue;
+            }
+            let list = if s == SlotKind::Starch { &sides.starches } else { &sides.vegs };
+            let pool = slot_candidates(sel, s, cuisine, list, arch);
+            out.minutes.push(easiest(&pool, arch));
+            out.cents = out.cents.saturating_add(self.budget.cheapest(&pool));
+        }
+        out
+    }
+
+    /// Minutes past the time limit and cents past the budget for `sel` plus
+    /// `reserve`; `(0, 0)` when it fits both.
+    pub fn overrun(&self, sel: &MealSelection, arch: Option<&Archetype>, reserve: &Reserve) -> (u32, u32) {
+        (minutes_over(sel, arch, &reserve.minutes, self.minutes()), self.budget.cents_over(sel, reserve.cents))
+    }
+
+    /// The items in `list` whose plate runs least past the time limit and
+    /// then the budget: every one that fits both, when any does.
+    fn least_over(
+        &self,
+        list: Vec<&'static Ingredient>,
+        over: impl Fn(&'static Ingredient) -> (u32, u32),
+    ) -> Vec<&'static Ingredient> {
+        if !self.limited() {
+            return list;
+        }
+        let scored: Vec<_> = list.into_iter().map(|i| (over(i), i)).collect();
+        let best = scored.iter().map(|(o, _)| *o).min();
+        scored.into_iter().filter(|(o, _)| Some(*o) == best).map(|(_, i)| i).collect()
+    }
+
+    /// Proteins from `list` that leave time and budget for sides in
+    /// `cuisine`, or the nearest misses when none do.
+    pub fn proteins_within_limits(&self, list: Vec<&'static Ingredient>, cuisine: Cuisine) -> Vec<&'static Ingredient> {
+        let sides = self.side_pools();
+        self.least_over(list, |p| {
+            let sel = MealSelection { protein: Some(p), ..MealSelection::default() };
+            self.overrun(&sel, None, &self.reserve(&sel, cuisine, None, None, &sides))
+        })
+    }
+
+    /// Candidates for `slot` that keep the plate, with room left for its
+    /// other empty sides, within the time limit and budget; the nearest
+    /// misses when none do.
+    pub fn within_limits(
+        &self,
+        pool: Vec<&'static Ingredient>,
+        sel: &MealSelection,
+        slot: SlotKind,
+        arch: Option<&Archetype>,
+    ) -> Vec<&'static Ingredient> {
+        if !self.limited() {
+            return pool;
+        }
+        let reserve = self.reserve(sel, self.cuisine, arch, Some(slot), &self.side_pools());
+        self.least_over(pool, |i| {
+            let mut cand = *sel;
+            set_item(&mut cand, slot, Some(i));
+            self.overrun(&cand, arch, &reserve)
+        })
+    }
+}
+
+/// Picks an archetype for the plate once its protein is known: one that
+/// accepts everything already on it and still has a starch to offer.
+pub fn choose_archetype(sel: &MealSelection, env: GenEnv, rng: &mut impl Rng) -> Option<&'static Archetype> {
+    let starches = env.pool(SlotKind::Starch);
+    let mut options: Vec<&'static Archetype> = archetypes_for(env.cuisine)
+        .filter(|a| archetype_accepts(a, sel))
+        .filter(|a| {
+            env.lock.starch || !slot_candidates(sel, SlotKind::Starch, env.cuisine, &starches, Some(a)).is_empty()
+        })
+        .collect();
+    // Under a time limit or budget, a slower method (smoking rather than
+    // grilling) or pricier pattern sits out while another fits better
+    if env.limited() {
+        let sides = env.side_pools();
+        let over: Vec<_> = options
+            .iter()
+            .map(|a| env.overrun(sel, Some(a), &env.reserve(sel, env.cuisine, Some(a), None, &sides)))
+            .collect();
+        let best = over.iter().min().copied();
+        let mut keep = over.into_iter().map(|o| Some(o) == best);
+        options.retain(|_| keep.next().unwrap_or(false));
+    }
+    options.choose(rng).copied()
+}
+
+/// Re-picks the archetype after a manual change (spec §7.5, option B):
+/// keeps `current` if it still accepts the plate, otherwise any that does.
+pub fn refit_archetype(
+    current: Option<&'static Archetype>,
+    sel: &MealSelection,
+    cuisine: Cuisine,
+    rng: &mut impl Rng,
+) -> Option<&'static Archetype> {
+    if let Some(a) = current.filter(|a| a.cuisine == cuisine && archetype_accepts(a, sel)) {
+        return Some(a);
+    }
+    let options: Vec<&'static Archetype> = archetypes_for(cuisine).filter(|a| archetype_accepts(a, sel)).collect();
+    options.choose(rng).copied()
+}
+
+/// Selection weight for `i` on this plate: the catalog weight, the
+/// household nudge, and the pair weight to every other item on it.
+pub fn plate_weight(i: &Ingredient, sel: &MealSelection, prefs: &Preferences) -> f64 {
+    let cat = catalog();
+    let mut w = f64::from(cat.weight(i.id));
+    if let Some(f) = prefs.frequency(i.id) {
+        w *= f64::from(f.percent()) / 100.0;
+    }
+    for o in [sel.protein, sel.starch, sel.veg1, sel.veg2].into_iter().flatten() {
+        if o.id != i.id {
+            w *= f64::from(cat.pair_weight(i.id, o.id)) / 100.0;
+        }
+    }
+    w
+}
+
+/// Weighted draw from `pool` against the rest of `sel`, skipping anything
+/// on cooldown and drawing from on-hand items first when the pantry is on,
+/// with in-season items and stock near its best-before date weighted up.
+/// The draw is even when the household switched weights off (or every
+/// weight is zero).
+pub fn choose_weighted(
+    pool: &[&'static Ingredient],
+    sel: &MealSelection,
+    env: GenEnv,
+    rng: &mut impl Rng,
+) -> Option<&'static Ingredient> {
+    let pool = env.pantry.first(&env.history.fresh(pool));
+    if env.prefs.uniform {
+        return pool.choose(rng).copied();
+    }
+    let weight = |i: &&'static Ingredient| plate_weight(i, sel, env.prefs) * env.pantry.urgency(i) * env.season.weight(i);
+    match pool.choose_weighted(rng, weight) {
+        Ok(i) => Some(*i),
+        Err(_) => pool.choose(rng).copied(),
+    }
+}
+
+/// Seeded weighted pick for a side, falling back to the plain pairing pool
+/// when the archetype leaves nothing for this slot. Sides that would push
+/// the plate past the time limit or budget, or that don't pair with the
+/// locked sides, sit out while others are left.
+pub fn pick_side(
+    sel: &MealSelection,
+    slot: SlotKind,
+    env: GenEnv,
+    arch: Option<&Archetype>,
+    rng: &mut impl Rng,
+) -> Option<&'static Ingredient> {
+    let list = env.pool(slot);
+    let fits = env.within_limits(env.candidates(sel, slot, &list, arch), sel, slot, arch);
+    choose_weighted(&pairs_with_locked(sel, slot, env, fits), sel, env, rng).or_else(|| {
+        let loose = env.within_limits(env.candidates(sel, slot, &list, None), sel, slot, arch);
+        choose_weighted(&pairs_with_locked(sel, slot, env, loose), sel, env, rng)
+    })
+}
+
+/// Per-serving totals for a plate. Items without nutrition data are left
+/// out of `total` and named in `missing` so the panel can say so.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct MealNutrition {
+    pub total: Nutrition,
+    pub counted: usize,
+    pub missing: Vec<&'static str>,
+}
+
+pub fn meal_nutrition(sel: &MealSelection) -> MealNutrition {
+    let mut out = MealNutrition::default();
+    for item in [sel.protein, sel.starch, sel.veg1, sel.veg2].into_iter().flatten() {
+        match catalog().nutrition(item.id) {
+            Some(n) => {
+                out.total += n;
+                out.counted += 1;
+            }
+            None => out.missing.push(item.name),
+        }
+    }
+    out
 }
 
-pub fn describe_meal(sel: &MealSelection) -> Option<String> {
+/// Phrasings for the plate description; the meal's seed picks one so the
+/// same seed always reads the same way.
+const DESC_FULL: &[&str] = &[
+    "This evening, we present {p} served alongside {s}, with a side of {v} \u{2014} complemented by {v2}.",
+    "Tonight's plate: {p} with {s}, plus {v} and {v2} on the side.",
+    "On the table tonight, {p} over {s}, joined by {v} and a little {v2}.",
+];
+const DESC_SHORT: &[&str] = &[
+    "This evening, we present {p} served alongside {s}, accompanied by {v}.",
+    "Tonight's plate: {p} with {s} and a side of {v}.",
+    "On the table tonight, {p} over {s}, with {v} to round it out.",
+];
+
+/// The plate in a sentence; a fusion plate's also names its cuisines.
+pub fn describe_meal(sel: &MealSelection, cuisine: Cuisine, seed: u64) -> Option<String> {
     let p = sel.protein?;
     let s = sel.starch?;
     let v = sel.veg1?;
-    match sel.veg2 {
-        Some(v2) => Some(format!(
-            "This evening, we present {} served alongside {}, \
-             with a side of {} \u{2014} complemented by {}.",
-            p.name, s.name, v.name, v2.name
-        )),
-        None => Some(format!(
-            "This evening, we present {} served alongside {}, \
-             accompanied by {}.",
-            p.name, s.name, v.name
-        )),
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let list = if sel.veg2.is_some() { DESC_FULL } else { DESC_SHORT };
+    let template = list.choose(&mut rng)?;
+    let desc = template
+        .replace("{p}", p.name)
+        .replace("{s}", s.name)
+        .replace("{v2}", sel.veg2.map_or("", |i| i.name))
+        .replace("{v}", v.name);
+    let others = fusion_cuisines(sel, cuisine);
+    if others.is_empty() {
+        return Some(desc);
     }
+    let names: Vec<&str> = others.into_iter().map(|c| catalog().label(c)).collect();
+    let home = catalog().label(cuisine);
+    Some(format!("{desc} {} the {home} way, with sides from {} cooking.", p.name, names.join(" and ")))
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
 
+    fn item(id: &str) -> &'static Ingredient {
+        catalog().ingredients.iter().copied().find(|i| i.id.as_str() == id).unwrap_or_else(|| panic!("no {id}"))
+    }
+
+    fn tortilla_plate() -> MealSelection {
+        MealSelection { starch: Some(item("tortillas")), veg1: Some(item("corn")), ..MealSelection::default() }
+    }
+
+    #[test]
+    fn a_locked_side_the_cuisine_lacks_is_a_conflict() {
+        let lock = LockState { starch: true, ..LockState::default() };
+        assert_eq!(cuisine_conflicts(&tortilla_plate(), lock, Cuisine::American), vec![SlotKind::Starch]);
+        assert_eq!(cuisine_conflicts(&tortilla_plate(), lock, Cuisine::Latin), vec![]);
+    }
+
+    #[test]
+    fn an_unlocked_side_never_conflicts() {
+        assert_eq!(cuisine_conflicts(&tortilla_plate(), LockState::default(), Cuisine::Asian), vec![]);
+    }
+
+    #[test]
+    fn suggested_cuisines_list_every_locked_item() {
+        let corn = LockState { veg1: true, ..LockState::default() };
+        assert_eq!(cuisines_for_locked(&tortilla_plate(), corn), vec![Cuisine::American, Cuisine::Latin, Cuisine::Bbq]);
+        let both = LockState { starch: true, veg1: true, ..LockState::default() };
+        assert_eq!(cuisines_for_locked(&tortilla_plate(), both), vec![Cuisine::Latin]);
+        assert_eq!(cuisines_for_locked(&tortilla_plate(), LockState::default()), catalog().cuisine_list());
+    }
+
+    #[test]
+    fn a_fusion_plate_names_both_cuisines() {
+        let sel = MealSelection {
+            protein: Some(item("chicken_breast")),
+            starch: Some(item("fried_rice")),
+            veg1: Some(item("collard_greens")),
+            veg2: None,
+        };
+        assert_eq!(fusion_cuisines(&sel, Cuisine::Asian), vec![Cuisine::American]);
+        let desc = describe_meal(&sel, Cuisine::Asian, 0).expect("a full plate");
+        assert!(desc.ends_with("Chicken Breast the Asian way, with sides from American cooking."), "{desc}");
+
+        let home = MealSelection { veg1: Some(item("bok_choy")), ..sel };
+        assert!(fusion_cuisines(&home, Cuisine::Asian).is_empty());
+        let desc = describe_meal(&home, Cuisine::Asian, 0).expect("a full plate");
+        assert!(!desc.contains("cooking."), "{desc}");
+    }
+
+    fn archetype(cuisine: Cuisine, id: &str) -> &'static Archetype {
+        archetypes_for(cuisine).find(|a| a.id == id).unwrap_or_else(|| panic!("no {id}"))
+    }
+
+    #[test]
+    fn refitting_keeps_an_archetype_that_still_fits() {
+        let steakhouse = archetype(Cuisine::American, "steakhouse");
+        let sel = MealSelection { protein: Some(item("steak")), starch: Some(item("baked_potato")), ..MealSelection::default() };
+        for seed in 0..20 {
+            let kept = refit_archetype(Some(steakhouse), &sel, Cuisine::American, &mut SmallRng::seed_from_u64(seed));
+            assert_eq!(kept.map(|a| a.id), Some("steakhouse"), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn refitting_drops_an_archetype_the_plate_left_behind() {
+        let steakhouse = archetype(Cuisine::American, "steakhouse");
+        let sel = MealSelection { protein: Some(item("chicken_breast")), starch: Some(item("pasta")), ..MealSelection::default() };
+        assert!(!archetype_accepts(steakhouse, &sel));
+        for seed in 0..20 {
+            let next = refit_archetype(Some(steakhouse), &sel, Cuisine::American, &mut SmallRng::seed_from_u64(seed));
+            assert!(next.is_none_or(|a| a.id != "steakhouse" && archetype_accepts(a, &sel)), "seed {seed}");
+        }
+        // A new cuisine always means a new archetype
+        let potatoes = MealSelection { starch: Some(item("baked_potato")), ..sel };
+        let moved = refit_archetype(Some(steakhouse), &potatoes, Cuisine::Bbq, &mut SmallRng::seed_from_u64(0));
+        assert!(moved.is_none_or(|a| a.cuisine == Cuisine::Bbq));
+    }
+}
@@ -4,9 +4,26 @@ mod meal_types;
 mod meal_slot;
 mod meal_generator;
 mod generator_logic;
+mod content_packs;
+mod preferences;
+mod history;
+mod pantry;
+mod budget;
+mod timing;
+mod rules;
+mod rules_panel;
+mod storage;
 mod icons;
+mod nutrition_panel;
+mod quality_engine;
+mod provenance;
+mod score_panel;
+mod week_plan;
+mod week_planner;
 
 pub use meal_generator::MealGenerator;
+pub use week_planner::WeekPlanner;
+pub use content_packs::load_served_packs;
 
 use dioxus::prelude::*;
 
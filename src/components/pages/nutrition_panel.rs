@@ -0,0 +1,40 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+
+use super::meal_types::{meal_nutrition, MealSelection};
+
+#[component]
+pub fn NutritionPanel(selection: MealSelection) -> Element {
+    let n = meal_nutrition(&selection);
+    if n.counted == 0 {
+        return rsx! {};
+    }
+    let t = n.total;
+    let stats = [
+        ("Calories", t.calories, "kcal"),
+        ("Protein", t.protein, "g"),
+        ("Carbs", t.carbs, "g"),
+        ("Fat", t.fat, "g"),
+        ("Fiber", t.fiber, "g"),
+        ("Sodium", t.sodium, "mg"),
+    ];
+    let missing = n.missing.join(", ");
+
+    rsx! {
+        div { class: "macros",
+            span { class: "macros__title", "Per Serving · approx." }
+            div { class: "macros__grid",
+                for (label, value, unit) in stats {
+                    div { class: "macros__cell",
+                        span { class: "macros__value", "{value}" span { class: "macros__unit", "{unit}" } }
+                        span { class: "macros__label", "{label}" }
+                    }
+                }
+            }
+            if !missing.is_empty() {
+                p { class: "macros__note", "Not counted (no data): {missing}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,567 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::content::{catalog, meal_amount, Amount, Category, Ingredient};
+
+use super::history::today;
+use super::meal_types::MealSelection;
+use super::storage;
+
+const STORAGE_KEY: &str = "mealgen.pantry";
+/// Stock within this many days of its best-before date is "use soon".
+pub const USE_SOON_DAYS: i64 = 3;
+/// Weight multiplier for "use soon" stock.
+pub const USE_SOON_WEIGHT: f64 = 4.0;
+/// Weight multiplier for stock at or past its best-before date.
+pub const USE_TODAY_WEIGHT: f64 = 10.0;
+/// Quantities below this count as used up.
+const EMPTY: f64 = 0.005;
+
+/// What's in the kitchen, how much and until when, with the pantry mode
+/// generation runs in.
+pub static PANTRY: GlobalSignal<Pantry> = Signal::global(Pantry::load);
+
+/// How much generation leans on what's already in the kitchen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PantryMode {
+    #[default]
+    Off,
+    /// Draw from on-hand items first, shop for the rest.
+    Prefer,
+    /// Only on-hand items; a slot with nothing on hand stays empty.
+    Require,
+    /// The protein must be on hand; sides are preferred.
+    ProteinOnly,
+}
+
+impl PantryMode {
+    pub const ALL: [PantryMode; 4] = [PantryMode::Off, PantryMode::Prefer, PantryMode::Require, PantryMode::ProteinOnly];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            PantryMode::Off => "Off",
+            PantryMode::Prefer => "Prefer",
+            PantryMode::Require => "Only on hand",
+            PantryMode::ProteinOnly => "Protein on hand",
+        }
+    }
+}
+
+/// One ingredient's stock, counted in the unit of its `buy_amount`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StockItem {
+    pub id: String,
+    pub qty: f64,
+    /// Local days since the Unix epoch, like history entries.
+    pub best_before: Option<i64>,
+}
+
+/// What a logged dinner took out of stock, so logging a different plate
+/// the same day can put it back first.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct Taken {
+    pub day: i64,
+    pub items: Vec<StockItem>,
+}
+
+/// What the household has in. IDs are stored as strings, like history, so
+/// stock survives a content pack being unloaded.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct Pantry {
+    pub mode: PantryMode,
+    #[serde(default)]
+    pub stock: Vec<StockItem>,
+    /// What tonight's logged dinner took, once one has been.
+    #[serde(default)]
+    pub taken: Option<Taken>,
+    /// Plain on-hand list from before quantities were tracked; read once
+    /// and folded into `stock` on load.
+    #[serde(default, skip_serializing)]
+    on_hand: Vec<String>,
+    /// Set on load; best-before dates are read relative to it.
+    #[serde(skip)]
+    pub today: i64,
+}
+
+impl Pantry {
+    pub fn entry(&self, i: &Ingredient) -> Option<&StockItem> {
+        self.stock.iter().find(|s| s.id == i.id.as_str())
+    }
+
+    pub fn has(&self, i: &Ingredient) -> bool {
+        self.entry(i).is_some_and(|s| s.qty > EMPTY)
+    }
+
+    pub fn add(&mut self, i: &Ingredient, qty: f64) {
+        self.add_stock(StockItem { id: i.id.as_str().to_string(), qty, best_before: None });
+    }
+
+    /// Adds `item.qty` to its stock, or stocks it with `item`'s date when
+    /// there's none.
+    fn add_stock(&mut self, item: StockItem) {
+        match self.stock.iter_mut().find(|s| s.id == item.id) {
+            // Hundredths are plenty and keep float dust out of the inputs
+            Some(s) => s.qty = ((s.qty + item.qty) * 100.0).round() / 100.0,
+            None => self.stock.push(item),
+        }
+    }
+
+    /// Sets the quantity outright; zero or less drops the item.
+    pub fn set_qty(&mut self, i: &Ingredient, qty: f64) {
+        let current = self.entry(i).map_or(0.0, |s| s.qty);
+        self.add(i, qty - current);
+        self.stock.retain(|s| s.qty > EMPTY);
+    }
+
+    pub fn set_best_before(&mut self, i: &Ingredient, day: Option<i64>) {
+        if let Some(s) = self.stock.iter_mut().find(|s| s.id == i.id.as_str()) {
+            s.best_before = day;
+        }
+    }
+
+    pub fn remove(&mut self, i: &Ingredient) {
+        self.stock.retain(|s| s.id != i.id.as_str());
+    }
+
+    /// Days until `i`'s best-before date; negative once it has passed.
+    pub fn days_left(&self, i: &Ingredient) -> Option<i64> {
+        self.entry(i).filter(|s| s.qty > EMPTY)?.best_before.map(|d| d - self.today)
+    }
+
+    /// Weight multiplier that pushes stock near its date onto the plate.
+    /// Off mode leaves weights alone.
+    pub fn urgency(&self, i: &Ingredient) -> f64 {
+        match self.days_left(i) {
+            _ if self.mode == PantryMode::Off => 1.0,
+            Some(d) if d <= 0 => USE_TODAY_WEIGHT,
+            Some(d) if d <= USE_SOON_DAYS => USE_SOON_WEIGHT,
+            _ => 1.0,
+        }
+    }
+
+    /// How much more of `i` a dinner needs than is in stock.
+    pub fn shortfall(&self, i: &Ingredient) -> Option<Amount> {
+        self.shortfall_of(i, meal_amount(i))
+    }
+
+    /// How much more of `i` than is in stock it takes to have `need`.
+    pub fn shortfall_of(&self, i: &Ingredient, need: Amount) -> Option<Amount> {
+        let have = self.entry(i).map_or(0.0, |s| s.qty);
+        (have + EMPTY < need.qty).then_some(Amount { qty: need.qty - have, ..need })
+    }
+
+    /// True when items of `category` must come from the pantry.
+    pub fn requires(&self, category: Category) -> bool {
+        match self.mode {
+            PantryMode::Require => true,
+            PantryMode::ProteinOnly => category == Category::Protein,
+            PantryMode::Off | PantryMode::Prefer => false,
+        }
+    }
+
+    /// `list` without the items the mode rules out.
+    pub fn allowed(&self, list: Vec<&'static Ingredient>) -> Vec<&'static Ingredient> {
+        list.into_iter().filter(|i| !self.requires(i.category) || self.has(i)).collect()
+    }
+
+    /// The on-hand part of `pool`, or all of it when nothing there is on
+    /// hand or the pantry is off.
+    pub fn first(&self, pool: &[&'static Ingredient]) -> Vec<&'static Ingredient> {
+        if self.mode == PantryMode::Off {
+            return pool.to_vec();
+        }
+        let on_hand: Vec<_> = pool.iter().copied().filter(|i| self.has(i)).collect();
+        if on_hand.is_empty() { pool.to_vec() } else { on_hand }
+    }
+
+    /// Items on the plate the stock doesn't cover, and how much to buy.
+    pub fn to_buy(&self, sel: &MealSelection) -> Vec<(&'static Ingredient, Amount)> {
+        [sel.protein, sel.starch, sel.veg1, sel.veg2]
+            .into_iter()
+            .flatten()
+            .filter_map(|i| self.shortfall(i).map(|a| (i, a)))
+            .collect()
+    }
+
+    /// Takes one dinner's worth of each item on the plate out of stock.
+    /// Tonight's dinner is cooked once: a plate already taken today goes
+    /// back into stock first.
+    pub fn cook(&mut self, sel: &MealSelection) {
+        if self.taken.as_ref().is_some_and(|t| t.day == self.today) {
+            self.restock();
+        }
+        let mut taken = Vec::new();
+        for i in [sel.protein, sel.starch, sel.veg1, sel.veg2].into_iter().flatten() {
+            if let Some(s) = self.entry(i) {
+                let out = StockItem { qty: s.qty.min(meal_amount(i).qty), ..s.clone() };
+                self.add(i, -out.qty);
+                taken.push(out);
+            }
+        }
+        self.stock.retain(|s| s.qty > EMPTY);
+        self.taken = Some(Taken { day: self.today, items: taken });
+    }
+
+    /// Puts back what the last logged dinner took out of stock.
+    fn restock(&mut self) {
+        for item in self.taken.take().map(|t| t.items).unwrap_or_default() {
+            self.add_stock(item);
+        }
+    }
+
+    pub fn load() -> Self {
+        let mut p: Pantry = storage::read_json(STORAGE_KEY).unwrap_or_default();
+        for id in std::mem::take(&mut p.on_hand) {
+            let qty = catalog().ingredients.iter().find(|i| i.id.as_str() == id).map_or(1.0, |i| meal_amount(i).qty);
+            p.stock.push(StockItem { id, qty, best_before: None });
+        }
+        p.today = today();
+        p
+    }
+
+    pub fn save(&self) {
+        storage::write_json(STORAGE_KEY, self);
+    }
+}
+
+/// Day number for an `<input type="date">` value ("2025-03-14"); dates
+/// that aren't on the calendar are None.
+fn day_from_iso(s: &str) -> Option<i64> {
+    let mut parts = s.splitn(3, '-').map(str::parse::<i64>);
+    let (Some(Ok(y)), Some(Ok(m)), Some(Ok(d))) = (parts.next(), parts.next(), parts.next()) else {
+        return None;
+    };
+    let leap = y % 4 == 0 && (y % 100 != 0 || y % 400 == 0);
+    let month_days = match m {
+        2 => 28 + i64::from(leap),
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return None,
+    };
+    if !(1..=month_days).contains(&d) {
+        return None;
+    }
+    // Days from civil (H. Hinnant), with March as the first month
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((m + 9) % 12) + 2) / 5 + d - 1;
+    Some(era * 146_097 + yoe * 365 + yoe / 4 - yoe / 100 + doy - 719_468)
+}
+
+fn iso_from_day(day: i64) -> String {
+    let z = day + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + i64::from(m <= 2);
+    format!("{y:04}-{m:02}-{d:02}")
+}
+
+/// "Still to buy" line under the plate; hidden while the pantry is off.
+#[component]
+pub fn ShoppingNote(pantry: Signal<Pantry>, selection: MealSelection) -> Element {
+    let p = pantry.read();
+    if p.mode == PantryMode::Off || selection.protein.is_none() {
+        return rsx! {};
+    }
+    let items: Vec<String> = p
+        .to_buy(&selection)
+        .into_iter()
+        .map(|(i, a)| if a.is_meals() { i.name.to_string() } else { format!("{} ({a})", i.name) })
+        .collect();
+
+    rsx! {
+        p { class: "pantry-note",
+            if items.is_empty() {
+                "Everything's on hand"
+            } else {
+                "Still to buy: {items.join(\", \")}"
+            }
+        }
+    }
+}
+
+#[component]
+pub fn PantrySettings(pantry: Signal<Pantry>) -> Element {
+    let p = pantry.read().clone();
+    let toggle_cls = |on: bool| if on { "cuisine-pill cuisine-pill--active" } else { "cuisine-pill" };
+    let groups = [("Proteins", Category::Protein), ("Starches", Category::Starch), ("Veg", Category::Veg)];
+    let mut stocked: Vec<&'static Ingredient> = catalog().ingredients.iter().copied().filter(|i| p.has(i)).collect();
+    stocked.sort_by_key(|i| p.days_left(i).unwrap_or(i64::MAX));
+    let soon = stocked.iter().filter(|i| p.days_left(i).is_some_and(|d| d <= USE_SOON_DAYS)).count();
+    let mut update = move |f: &dyn Fn(&mut Pantry)| {
+        let mut w = pantry.write();
+        f(&mut w);
+        w.save();
+    };
+
+    rsx! {
+        div { class: "household",
+            span { class: "household__title", "Pantry" }
+            div { class: "household__row",
+                span { class: "household__label", "Cook from" }
+                for m in PantryMode::ALL {
+                    button {
+                        class: toggle_cls(p.mode == m),
+                        onclick: move |_| update(&|w| w.mode = m),
+                        "{m.label()}"
+                    }
+                }
+            }
+            details { class: "pantry",
+                summary { class: "pantry__summary",
+                    if stocked.len() == 1 { "1 item in stock" } else { "{stocked.len()} items in stock" }
+                    if soon > 0 { " \u{00B7} {soon} to use soon" }
+                }
+                if !stocked.is_empty() {
+                    ul { class: "pantry__stock",
+                        for i in stocked {
+                            {render_stock(i, &p, pantry)}
+                        }
+                    }
+                }
+                for (label, cat) in groups {
+                    div { class: "household__row",
+                        span { class: "household__label", "{label}" }
+                        for i in catalog().by_category(cat).into_iter().filter(|i| !p.has(i)) {
+                            button {
+                                class: "cuisine-pill",
+                                title: "Add {meal_amount(i)}",
+                                onclick: move |_| update(&|w| w.add(i, meal_amount(i).qty)),
+                                "+ {i.name}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_stock(i: &'static Ingredient, p: &Pantry, mut pantry: Signal<Pantry>) -> Element {
+    let buy = meal_amount(i);
+    let qty = p.entry(i).map_or(0.0, |s| s.qty);
+    let unit = Amount { qty, ..buy }.unit_label();
+    let date = p.entry(i).and_then(|s| s.best_before).map(iso_from_day).unwrap_or_default();
+    let badge = match p.days_left(i) {
+        Some(d) if d < 0 => Some("Past best-before"),
+        Some(0) => Some("Use today"),
+        Some(d) if d <= USE_SOON_DAYS => Some("Use soon"),
+        _ => None,
+    };
+    let mut update = move |f: &dyn Fn(&mut Pantry)| {
+        let mut w = pantry.write();
+        f(&mut w);
+        w.save();
+    };
+
+    rsx! {
+        li { class: "pantry__item",
+            span { class: "pantry__name", "{i.name}" }
+            input {
+                class: "pantry__qty",
+                r#type: "number",
+                min: "0",
+                step: "any",
+                value: "{qty}",
+                onchange: move |e| {
+                    if let Ok(q) = e.value().parse::<f64>() {
+                        update(&|w| w.set_qty(i, q));
+                    }
+                },
+            }
+            span { class: "pantry__unit", "{unit}" }
+            button {
+                class: "cuisine-pill",
+                title: "Bought another {buy}",
+                onclick: move |_| update(&|w| w.add(i, buy.qty)),
+                "+{buy.qty}"
+            }
+            input {
+                class: "pantry__date",
+                r#type: "date",
+                title: "Best before",
+                value: "{date}",
+                onchange: move |e| {
+                    let day = day_from_iso(&e.value());
+                    update(&|w| w.set_best_before(i, day));
+                },
+            }
+            if let Some(b) = badge {
+                span { class: "meal-slot__badge pantry__soon", "{b}" }
+            }
+            button {
+                class: "pantry__remove",
+                title: "Remove from the pantry",
+                onclick: move |_| update(&|w| w.remove(i)),
+                "\u{00D7}"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_epoch_is_day_zero() {
+        assert_eq!(day_from_iso("1970-01-01"), Some(0));
+        assert_eq!(iso_from_day(0), "1970-01-01");
+        assert_eq!(iso_from_day(-1), "1969-12-31");
+    }
+
+    #[test]
+    fn dates_round_trip_across_edges() {
+        let dates = [
+            "2024-02-28", "2024-02-29", "2024-03-01", "2023-02-28", "2023-03-01", "2000-02-29", "2000-03-01",
+            "1900-02-28", "1900-03-01", "2100-02-28", "2100-03-01", "2024-12-31", "2025-01-01", "1999-12-31",
+            "2000-01-01", "2025-04-30", "2025-05-01", "2025-06-30", "2025-07-31", "2025-08-01", "1969-12-31",
+        ];
+        for iso in dates {
+            let day = day_from_iso(iso).unwrap_or_else(|| panic!("{iso} didn't parse"));
+            assert_eq!(iso_from_day(day), iso);
+        }
+    }
+
+    #[test]
+    fn consecutive_days_are_one_apart() {
+        let pairs = [
+            ("2024-02-28", "2024-02-29"),
+            ("2024-02-29", "2024-03-01"),
+            ("2023-02-28", "2023-03-01"),
+            ("1900-02-28", "1900-03-01"),
+            ("2000-02-28", "2000-02-29"),
+            ("2024-12-31", "2025-01-01"),
+            ("2025-01-31", "2025-02-01"),
+            ("2025-04-30", "2025-05-01"),
+        ];
+        for (a, b) in pairs {
+            assert_eq!(day_from_iso(b).zip(day_from_iso(a)).map(|(b, a)| b - a), Some(1), "{a} to {b}");
+        }
+    }
+
+    #[test]
+    fn every_day_of_four_centuries_round_trips() {
+        let (Some(start), Some(end)) = (day_from_iso("1900-01-01"), day_from_iso("2300-01-01")) else {
+            panic!("bounds didn't parse");
+        };
+        assert_eq!(end - start, 146_097);
+        for day in start..end {
+            assert_eq!(day_from_iso(&iso_from_day(day)), Some(day));
+        }
+    }
+
+    fn item(id: &str) -> &'static Ingredient {
+        catalog().ingredients.iter().copied().find(|i| i.id.as_str() == id).unwrap_or_else(|| panic!("no {id}"))
+    }
+
+    fn qty(p: &Pantry, id: &str) -> Option<f64> {
+        p.entry(item(id)).map(|s| s.qty)
+    }
+
+    #[test]
+    fn cooking_takes_a_dinner_out_of_stock() {
+        let mut p = Pantry { today: 100, ..Pantry::default() };
+        p.add(item("steak"), 3.0);
+        p.add(item("broccoli"), 0.5);
+        p.cook(&MealSelection { protein: Some(item("steak")), veg1: Some(item("broccoli")), ..MealSelection::default() });
+        assert_eq!(qty(&p, "steak"), Some(1.0));
+        assert_eq!(qty(&p, "broccoli"), None);
+    }
+
+    /// Logging a second plate the same night swaps the first one's stock
+    /// back in before taking the new one's.
+    #[test]
+    fn relogging_tonight_restores_the_first_plate() {
+        let mut p = Pantry { today: 100, ..Pantry::default() };
+        p.add(item("steak"), 3.0);
+        p.add(item("chicken_breast"), 5.0);
+        p.add(item("broccoli"), 0.5);
+        p.set_best_before(item("broccoli"), Some(102));
+
+        let steak = MealSelection { protein: Some(item("steak")), veg1: Some(item("broccoli")), ..MealSelection::default() };
+        p.cook(&steak);
+        assert_eq!(qty(&p, "broccoli"), None);
+        let chicken = MealSelection { protein: Some(item("chicken_breast")), ..MealSelection::default() };
+        p.cook(&chicken);
+        assert_eq!(qty(&p, "steak"), Some(3.0));
+        assert_eq!(qty(&p, "chicken_breast"), Some(2.5));
+        assert_eq!(p.entry(item("broccoli")).and_then(|s| s.best_before), Some(102));
+        assert_eq!(qty(&p, "broccoli"), Some(0.5));
+
+        // Tomorrow's dinner leaves tonight's alone
+        p.today = 101;
+        p.cook(&steak);
+        assert_eq!(qty(&p, "chicken_breast"), Some(2.5));
+        assert_eq!(qty(&p, "steak"), Some(1.0));
+    }
+
+    #[test]
+    fn malformed_dates_are_none() {
+        assert_eq!(day_from_iso(""), None);
+        assert_eq!(day_from_iso("2025-03"), None);
+        assert_eq!(day_from_iso("march 14"), None);
+    }
+
+    #[test]
+    fn dates_off_the_calendar_are_none() {
+        for iso in ["2025-13-45", "2025-00-10", "2025-04-31", "2025-02-29", "1900-02-29", "2025-01-00", "2025-01-32"] {
+            assert_eq!(day_from_iso(iso), None, "{iso}");
+        }
+        assert!(day_from_iso("2000-02-29").is_some());
+    }
+
+    #[test]
+    fn stock_near_its_date_weighs_more() {
+        let mut p = Pantry { mode: PantryMode::Prefer, today: 100, ..Pantry::default() };
+        for (id, best_before) in [("steak", 99), ("salmon", 100), ("broccoli", 103), ("corn", 104)] {
+            p.add(item(id), 1.0);
+            p.set_best_before(item(id), Some(best_before));
+        }
+        p.add(item("rice_pilaf"), 1.0);
+        assert_eq!(p.urgency(item("steak")), USE_TODAY_WEIGHT);
+        assert_eq!(p.urgency(item("salmon")), USE_TODAY_WEIGHT);
+        assert_eq!(p.urgency(item("broccoli")), USE_SOON_WEIGHT);
+        assert_eq!(p.urgency(item("corn")), 1.0);
+        assert_eq!(p.urgency(item("rice_pilaf")), 1.0);
+        assert_eq!(p.urgency(item("chicken_breast")), 1.0);
+
+        p.mode = PantryMode::Off;
+        assert_eq!(p.urgency(item("steak")), 1.0);
+    }
+
+    #[test]
+    fn only_on_hand_needs_every_slot_stocked() {
+        let mut p = Pantry { mode: PantryMode::Require, ..Pantry::default() };
+        p.add(item("steak"), 0.5);
+        let pool = vec![item("steak"), item("chicken_breast"), item("broccoli")];
+        assert_eq!(p.allowed(pool), vec![item("steak")]);
+        assert!(p.requires(Category::Protein) && p.requires(Category::Veg));
+
+        let sel = MealSelection { protein: Some(item("steak")), veg1: Some(item("broccoli")), ..MealSelection::default() };
+        let short: Vec<_> = p.to_buy(&sel).into_iter().map(|(i, a)| (i.id.as_str(), a.qty)).collect();
+        assert_eq!(short, vec![("steak", meal_amount(item("steak")).qty - 0.5), ("broccoli", meal_amount(item("broccoli")).qty)]);
+    }
+
+    #[test]
+    fn protein_on_hand_leaves_sides_to_shop_for() {
+        let mut p = Pantry { mode: PantryMode::ProteinOnly, ..Pantry::default() };
+        p.add(item("steak"), 5.0);
+        let pool = vec![item("steak"), item("chicken_breast"), item("broccoli"), item("corn")];
+        assert_eq!(p.allowed(pool), vec![item("steak"), item("broccoli"), item("corn")]);
+        assert!(p.requires(Category::Protein) && !p.requires(Category::Veg));
+
+        let sel = MealSelection { protein: Some(item("steak")), veg1: Some(item("broccoli")), ..MealSelection::default() };
+        assert_eq!(p.shortfall(item("steak")), None);
+        let short: Vec<_> = p.to_buy(&sel).into_iter().map(|(i, _)| i.id.as_str()).collect();
+        assert_eq!(short, vec!["broccoli"]);
+    }
+}
@@ -0,0 +1,329 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::content::{catalog, Allergen, Cuisine, DietFilter, Ingredient, IngredientId, Lifestyle};
+
+use super::meal_types::in_cuisine;
+use super::storage;
+use super::timing::DONE_IN_CHOICES;
+
+const STORAGE_KEY: &str = "mealgen.prefs";
+
+/// A household nudge on how often an item should come up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Frequency {
+    Less,
+    More,
+}
+
+impl Frequency {
+    /// Percentage applied to the catalog weight.
+    pub const fn percent(self) -> u32 {
+        match self {
+            Frequency::Less => 35,
+            Frequency::More => 250,
+        }
+    }
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Frequency::Less => "Less often",
+            Frequency::More => "More often",
+        }
+    }
+
+    pub const fn arrow(self) -> &'static str {
+        match self {
+            Frequency::Less => "\u{25BE}",
+            Frequency::More => "\u{25B4}",
+        }
+    }
+}
+
+/// The household's diet, allergies and generation settings; the
+/// generator and the week planner both honour them.
+pub static PREFS: GlobalSignal<Preferences> = Signal::global(Preferences::load);
+
+/// Household settings every generation path honours.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    pub diet: DietFilter,
+    /// Hard exclusions; an item with unknown allergens counts as a conflict.
+    pub allergies: Vec<Allergen>,
+    /// Ignore catalog weights and nudges and pick every candidate evenly.
+    pub uniform: bool,
+    /// Nudges by ingredient ID, kept as strings like history so nudges on
+    /// pack items survive until the pack has loaded.
+    pub frequency: Vec<(String, Frequency)>,
+    /// "Done in N minutes": plates cooked in parallel must finish in time.
+    pub done_in: Option<u32>,
+    /// Sides may pair with the protein in another cuisine than the plate's.
+    pub fusion: bool,
+    /// Leave out-of-season produce out of generation rather than just
+    /// drawing in-season items more often.
+    pub in_season_only: bool,
+}
+
+impl Preferences {
+    /// Why `i` is filtered out, or `None` when it may be generated.
+    pub fn blocked(&self, i: &Ingredient) -> Option<&'static str> {
+        match self.allergen_conflicts(i) {
+            Some(found) if found.is_empty() => self.diet.violation(catalog().diet(i.id)),
+            Some(found) => found.first().map(|a| a.warning()),
+            None => Some("Allergens unknown"),
+        }
+    }
+
+    /// Allergens in `i` the household avoids; `None` when the profile is
+    /// set and the item has no allergen data.
+    pub fn allergen_conflicts(&self, i: &Ingredient) -> Option<Vec<Allergen>> {
+        if self.allergies.is_empty() {
+            return Some(Vec::new());
+        }
+        let entry = catalog().allergens(i.id)?;
+        Some(entry.allergens.iter().copied().filter(|a| self.allergies.contains(a)).collect())
+    }
+
+    /// Card badge text for a manually picked item that trips an allergy.
+    pub fn allergen_badge(&self, i: &Ingredient) -> Option<String> {
+        match self.allergen_conflicts(i) {
+            Some(found) if found.is_empty() => None,
+            Some(found) => {
+                let names: Vec<&str> = found.iter().map(|a| a.label()).collect();
+                Some(format!("Contains {}", names.join(", ")))
+            }
+            None => Some("Allergens unknown".to_string()),
+        }
+    }
+
+    pub fn is_restricted(&self) -> bool {
+        self.diet.is_restricted() || !self.allergies.is_empty()
+    }
+
+    /// Short name for notices, e.g. "vegetarian, allergy-safe".
+    pub fn describe(&self) -> String {
+        let diet = self.diet.describe();
+        match (diet.is_empty(), self.allergies.is_empty()) {
+            (_, true) => diet,
+            (true, false) => "allergy-safe".to_string(),
+            (false, false) => format!("{diet}, allergy-safe"),
+        }
+    }
+
+    pub fn toggle_allergy(&mut self, a: Allergen) {
+        if let Some(pos) = self.allergies.iter().position(|x| *x == a) {
+            self.allergies.remove(pos);
+        } else {
+            self.allergies.push(a);
+        }
+    }
+
+    pub fn frequency(&self, id: IngredientId) -> Option<Frequency> {
+        self.frequency.iter().find(|(i, _)| i == id.as_str()).map(|(_, f)| *f)
+    }
+
+    /// Sets the nudge for `id`; choosing the one already set clears it.
+    pub fn toggle_frequency(&mut self, id: IngredientId, f: Frequency) {
+        let current = self.frequency(id);
+        self.frequency.retain(|(i, _)| i != id.as_str());
+        if current != Some(f) {
+            self.frequency.push((id.as_str().to_string(), f));
+        }
+    }
+
+    pub fn allowed(&self, list: Vec<&'static Ingredient>) -> Vec<&'static Ingredient> {
+        list.into_iter().filter(|i| self.blocked(i).is_none()).collect()
+    }
+
+    pub fn load() -> Self {
+        storage::read_json(STORAGE_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        storage::write_json(STORAGE_KEY, self);
+    }
+
+    /// Cuisines with at least one protein that passes the filters.
+    pub fn cuisines_with_protein(&self) -> Vec<Cuisine> {
+        let proteins = self.allowed(crate::content::get_proteins());
+        catalog()
+            .cuisine_list()
+            .into_iter()
+            .filter(|&c| !in_cuisine(c, &proteins).is_empty())
+            .collect()
+    }
+}
+
+/// Applies `f` to the household settings and saves them.
+pub fn update(mut prefs: Signal<Preferences>, f: impl FnOnce(&mut Preferences)) {
+    let mut w = prefs.write();
+    f(&mut w);
+    w.save();
+}
+
+#[component]
+pub fn HouseholdSettings(prefs: Signal<Preferences>) -> Element {
+    let diet = prefs.read().diet;
+    let allergies = prefs.read().allergies.clone();
+    let uniform = prefs.read().uniform;
+    let done_in = prefs.read().done_in;
+    let fusion = prefs.read().fusion;
+    let in_season_only = prefs.read().in_season_only;
+    let nudged: Vec<(IngredientId, Frequency, &'static str)> = prefs
+        .read()
+        .frequency
+        .iter()
+        .filter_map(|(id, f)| catalog().ingredients.iter().find(|i| i.id.as_str() == id).map(|i| (i.id, *f, i.name)))
+        .collect();
+    let toggle_cls = |on: bool| if on { "cuisine-pill cuisine-pill--active" } else { "cuisine-pill" };
+    let gf_cls = toggle_cls(diet.gluten_free);
+    let df_cls = toggle_cls(diet.dairy_free);
+
+    rsx! {
+        div { class: "household",
+            span { class: "household__title", "Household" }
+            div { class: "household__row",
+                span { class: "household__label", "Diet" }
+                for l in Lifestyle::ALL {
+                    button {
+                        class: toggle_cls(diet.lifestyle == l),
+                        onclick: move |_| update(prefs, |p| p.diet.lifestyle = l),
+                        "{l.label()}"
+                    }
+                }
+            }
+            div { class: "household__row",
+                span { class: "household__label", "Avoid" }
+                button {
+                    class: "{gf_cls}",
+                    onclick: move |_| update(prefs, |p| p.diet.gluten_free = !p.diet.gluten_free),
+                    "Gluten-free"
+                }
+                button {
+                    class: "{df_cls}",
+                    onclick: move |_| update(prefs, |p| p.diet.dairy_free = !p.diet.dairy_free),
+                    "Dairy-free"
+                }
+            }
+            div { class: "household__row",
+                span { class: "household__label", "Allergies" }
+                for a in Allergen::ALL {
+                    button {
+                        class: toggle_cls(allergies.contains(&a)),
+                        onclick: move |_| update(prefs, |p| p.toggle_allergy(a)),
+                        "{a.label()}"
+                    }
+                }
+            }
+            div { class: "household__row",
+                span { class: "household__label", "Picks" }
+                button {
+                    class: toggle_cls(!uniform),
+                    title: "Everyday items come up more than occasional ones",
+                    onclick: move |_| update(prefs, |p| p.uniform = false),
+                    "Weighted"
+                }
+                button {
+                    class: toggle_cls(uniform),
+                    title: "Every candidate is equally likely",
+                    onclick: move |_| update(prefs, |p| p.uniform = true),
+                    "Uniform"
+                }
+            }
+            div { class: "household__row",
+                span { class: "household__label", "Cuisines" }
+                button {
+                    class: toggle_cls(!fusion),
+                    title: "Every item on the plate comes from one cuisine",
+                    onclick: move |_| update(prefs, |p| p.fusion = false),
+                    "One per plate"
+                }
+                button {
+                    class: toggle_cls(fusion),
+                    title: "Sides may come from another cuisine that pairs them with the protein",
+                    onclick: move |_| update(prefs, |p| p.fusion = true),
+                    "Fusion"
+                }
+            }
+            div { class: "household__row",
+                span { class: "household__label", "Produce" }
+                button {
+                    class: toggle_cls(!in_season_only),
+                    title: "In-season sides come up more often; others still can",
+                    onclick: move |_| update(prefs, |p| p.in_season_only = false),
+                    "Prefer in season"
+                }
+                button {
+                    class: toggle_cls(in_season_only),
+                    title: "Out-of-season sides are never generated",
+                    onclick: move |_| update(prefs, |p| p.in_season_only = true),
+                    "In season only"
+                }
+            }
+            div { class: "household__row",
+                span { class: "household__label", "Done in" }
+                button {
+                    class: toggle_cls(done_in.is_none()),
+                    onclick: move |_| update(prefs, |p| p.done_in = None),
+                    "Any time"
+                }
+                for m in DONE_IN_CHOICES {
+                    button {
+                        class: toggle_cls(done_in == Some(m)),
+                        onclick: move |_| update(prefs, |p| p.done_in = Some(m)),
+                        "{m} min"
+                    }
+                }
+            }
+            if !nudged.is_empty() {
+                div { class: "household__row",
+                    span { class: "household__label", "Nudged" }
+                    for (id, f, name) in nudged {
+                        button {
+                            class: "cuisine-pill cuisine-pill--active",
+                            title: "{f.label()} \u{2014} click to reset",
+                            onclick: move |_| update(prefs, |p| p.toggle_frequency(id, f)),
+                            "{name} {f.arrow()}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Settings load before any content pack, so a nudge on a pack item
+    /// has to come back from storage as it went in.
+    #[test]
+    fn nudges_on_unknown_items_survive_a_reload() {
+        let saved = r#"{"frequency":[["steak","More"],["pack_only_item","Less"]]}"#;
+        let prefs: Preferences = serde_json::from_str(saved).unwrap_or_else(|e| panic!("{e}"));
+        assert_eq!(prefs.frequency.len(), 2);
+        let resaved = serde_json::to_string(&prefs).unwrap_or_else(|e| panic!("{e}"));
+        assert!(resaved.contains(r#"["pack_only_item","Less"]"#), "{resaved}");
+    }
+
+    fn item(id: &str) -> &'static Ingredient {
+        catalog().ingredients.iter().copied().find(|i| i.id.as_str() == id).unwrap_or_else(|| panic!("no {id}"))
+    }
+
+    #[test]
+    fn a_picked_allergen_is_badged() {
+        let caesar = item("caesar_salad");
+        let fish = Preferences { allergies: vec![Allergen::Fish], ..Preferences::default() };
+        assert_eq!(fish.allergen_badge(caesar), Some("Contains Fish".to_string()));
+        assert_eq!(fish.blocked(caesar), Some("Allergen: fish"));
+        assert_eq!(fish.allergen_badge(item("broccoli")), None);
+
+        let two = Preferences { allergies: vec![Allergen::Fish, Allergen::Dairy], ..Preferences::default() };
+        assert_eq!(two.allergen_badge(caesar), Some("Contains Dairy, Fish".to_string()));
+        assert_eq!(Preferences::default().allergen_badge(caesar), None);
+    }
+}
@@ -0,0 +1,336 @@
+#![allow(non_snake_case)]
+
+// Provenance — why each slot holds what it holds
+//
+// Each slot's record is taken when the slot is filled: how it was filled
+// (`Origin`), which way the pairing with the protein runs, the cuisine, and
+// the constraints and score rules that had a say under the settings of the
+// moment. Changing a setting afterwards doesn't rewrite why an item was
+// chosen; only the lock is read live, since it can change without a refill.
+// A new protein or cuisine does change what a kept item sits next to, so the
+// items it leaves in place are re-recorded with the origin they had.
+
+use dioxus::prelude::*;
+
+use crate::content::{catalog, Archetype, Cuisine, Ingredient};
+
+use super::budget::format_cents;
+use super::meal_types::{
+    fits_archetype, get_item, is_locked, side_cuisine, GenEnv, MealSelection, Pairing, SlotKind,
+};
+use super::pantry::PantryMode;
+use super::preferences::Frequency;
+use super::quality_engine::{score_meal, ScoreLine, CANDIDATE_N, REROLL_SLACK};
+use super::timing::{meal_time, minutes_over};
+
+/// How a slot came to hold its item. `sampled` counts the different side
+/// combinations a generated or refilled plate was picked from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Origin {
+    /// Drawn by Generate.
+    Generated { sampled: usize },
+    /// Locked, so Generate left it alone.
+    Kept,
+    Rerolled,
+    Picked,
+    /// Refilled around a protein that was picked or rerolled.
+    Cascaded { sampled: usize },
+}
+
+impl Origin {
+    pub const fn describe(self) -> &'static str {
+        match self {
+            Origin::Generated { .. } => "Generated",
+            Origin::Kept => "Kept through Generate",
+            Origin::Rerolled => "Re-rolled",
+            Origin::Picked => "Picked by hand",
+            Origin::Cascaded { .. } => "Refilled around a new protein",
+        }
+    }
+}
+
+/// The record each slot's item was filled with, in `SlotKind::ALL` order.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Provenances([Option<Provenance>; 4]);
+
+impl Provenances {
+    /// After Generate: locked slots were kept, the rest drawn.
+    pub fn generated(sel: &MealSelection, env: GenEnv, arch: Option<&Archetype>, sampled: usize) -> Self {
+        let mut out = Provenances::default();
+        for s in SlotKind::ALL {
+            let origin = if is_locked(env.lock, s) { Origin::Kept } else { Origin::Generated { sampled } };
+            out.set(sel, s, origin, env, arch);
+        }
+        out
+    }
+
+    /// The record for `slot`, while it still describes `item`.
+    pub fn get(&self, slot: SlotKind, item: &Ingredient) -> Option<&Provenance> {
+        self.0.get(slot.index() as usize)?.as_ref().filter(|p| p.item.id == item.id)
+    }
+
+    pub fn set(&mut self, sel: &MealSelection, slot: SlotKind, origin: Origin, env: GenEnv, arch: Option<&Archetype>) {
+        if let Some(p) = self.0.get_mut(slot.index() as usize) {
+            *p = provenance(sel, slot, origin, env, arch);
+        }
+    }
+
+    /// Records the unlocked sides as refilled after a protein change; the
+    /// locked ones are re-recorded against the new protein.
+    pub fn cascade(&mut self, sel: &MealSelection, env: GenEnv, arch: Option<&Archetype>, sampled: usize) {
+        for s in [SlotKind::Starch, SlotKind::Veg1, SlotKind::Veg2] {
+            if is_locked(env.lock, s) {
+                self.refresh(sel, s, env, arch);
+            } else {
+                self.set(sel, s, Origin::Cascaded { sampled }, env, arch);
+            }
+        }
+    }
+
+    /// Re-records every slot except `skip` after the plate's cuisine
+    /// changed under it.
+    pub fn recuisine(&mut self, sel: &MealSelection, skip: Option<SlotKind>, env: GenEnv, arch: Option<&Archetype>) {
+        for s in SlotKind::ALL.into_iter().filter(|&s| Some(s) != skip) {
+            self.refresh(sel, s, env, arch);
+        }
+    }
+
+    /// Re-records `slot` with the origin it already has, so its pairing and
+    /// cuisine follow the plate; a record for another item is left as is.
+    fn refresh(&mut self, sel: &MealSelection, slot: SlotKind, env: GenEnv, arch: Option<&Archetype>) {
+        let origin = get_item(sel, slot).and_then(|item| self.get(slot, item)).map(|p| p.origin);
+        if let Some(origin) = origin {
+            self.set(sel, slot, origin, env, arch);
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Provenance {
+    pub item: &'static Ingredient,
+    pub origin: Origin,
+    pub cuisine: Cuisine,
+    /// How a side pairs with the protein, and in which cuisine; `None` for
+    /// the protein itself or a plate without one.
+    pub pairing: Option<(&'static Ingredient, Pairing, Cuisine)>,
+    pub locked: bool,
+    /// Constraints that had a say, in words.
+    pub reasons: Vec<String>,
+    /// Score rules that mention the item.
+    pub scores: Vec<ScoreLine>,
+}
+
+/// The provenance record for `slot` on `sel`, or `None` when it's empty.
+fn provenance(
+    sel: &MealSelection,
+    slot: SlotKind,
+    origin: Origin,
+    env: GenEnv,
+    arch: Option<&Archetype>,
+) -> Option<Provenance> {
+    let item = get_item(sel, slot)?;
+    let pairing = sel.protein.filter(|_| slot != SlotKind::Protein).map(|p| {
+        let k = side_cuisine(p, item, env.cuisine).unwrap_or(env.cuisine);
+        (p, Pairing::of(p, item, k), k)
+    });
+    let scores = score_meal(sel, env.cuisine, arch).lines.into_iter().filter(|l| l.items.contains(&item.id)).collect();
+    Some(Provenance {
+        item,
+        origin,
+        cuisine: env.cuisine,
+        pairing,
+        locked: is_locked(env.lock, slot),
+        reasons: reasons(sel, slot, item, origin, env, arch),
+        scores,
+    })
+}
+
+fn reasons(
+    sel: &MealSelection,
+    slot: SlotKind,
+    item: &'static Ingredient,
+    origin: Origin,
+    env: GenEnv,
+    arch: Option<&Archetype>,
+) -> Vec<String> {
+    let mut out = Vec::new();
+    match origin {
+        Origin::Generated { sampled } | Origin::Cascaded { sampled } if slot != SlotKind::Protein => match sampled {
+            0 => {}
+            1 => out.push(format!("The only plate {CANDIDATE_N} samples came to")),
+            n => out.push(format!("Best of {n} different plates from {CANDIDATE_N} samples")),
+        },
+        Origin::Rerolled if slot != SlotKind::Protein => {
+            out.push(format!("Drawn from swaps within {REROLL_SLACK} points of the best"));
+        }
+        _ => {}
+    }
+    if slot == SlotKind::Protein {
+        if let Some((_, text)) = env.rules.cuisine.as_ref().filter(|(c, _)| *c == env.cuisine) {
+            out.push(format!("\u{201C}{text}\u{201D} set the cuisine"));
+        }
+    }
+    if let Some(a) = arch {
+        if fits_archetype(a, sel, slot, item) {
+            out.push(format!("Fits a {}", a.label));
+        } else {
+            out.push(format!("Outside the {} pattern", a.label));
+        }
+    }
+    if env.prefs.uniform {
+        out.push("Weights are off, so every candidate was even".to_string());
+    } else if let Some(f) = env.prefs.frequency(item.id) {
+        let how = if f == Frequency::More { "more" } else { "less" };
+        out.push(format!("You asked to see it {how} often"));
+    }
+    if let Some(reason) = env.prefs.blocked(item) {
+        out.push(reason.to_string());
+    }
+    if let Some(text) = env.rules.excluded_by(item) {
+        out.push(format!("Breaks \u{201C}{text}\u{201D}"));
+    }
+    if env.history.on_cooldown(item) {
+        out.push("Cooked recently".to_string());
+    }
+    if slot != SlotKind::Protein && env.season.month != 0 {
+        out.push(match (env.season.in_season(item), env.prefs.in_season_only) {
+            (true, true) => "In season this month",
+            (true, false) => "In season this month, so drawn more often",
+            (false, false) => "Out of season this month",
+            (false, true) => "Out of season this month, so Generate skips it",
+        }.to_string());
+    }
+    if env.pantry.mode != PantryMode::Off && env.pantry.has(item) {
+        out.push(match env.pantry.days_left(item) {
+            Some(d) if d <= 0 => "On hand and due today".to_string(),
+            Some(d) if env.pantry.urgency(item) > 1.0 => format!("On hand, best before in {d} days"),
+            _ => "On hand in the pantry".to_string(),
+        });
+    }
+    if let Some(max) = env.minutes() {
+        let over = minutes_over(sel, arch, &[], Some(max));
+        let total = meal_time(sel, arch).minutes.total;
+        out.push(if over == 0 {
+            format!("Plate fits the {max} min limit (about {total} min)")
+        } else {
+            format!("Plate runs {over} min past the {max} min limit")
+        });
+    }
+    if let Some(max) = env.budget.max_cents {
+        let cost = env.budget.meal_cost(sel).cents;
+        let price = env.budget.price(item).map(|c| format!("; this is {} of it", format_cents(c))).unwrap_or_default();
+        out.push(if cost <= max {
+            format!("Plate costs {} of the {} cap{price}", format_cents(cost), format_cents(max))
+        } else {
+            format!("Plate runs {} over the {} cap{price}", format_cents(cost - max), format_cents(max))
+        });
+    }
+    out
+}
+
+fn pairing_line(p: &Ingredient, item: &Ingredient, pairing: Pairing, cuisine: &str) -> String {
+    match pairing {
+        Pairing::Forward => format!("{} lists {} as a {cuisine} pair", p.name, item.name),
+        Pairing::Reverse => format!("{} lists {} as a {cuisine} pair", item.name, p.name),
+        Pairing::Both => format!("{} and {} list each other in {cuisine}", p.name, item.name),
+        Pairing::Unpaired => format!("Doesn't pair with {} in any cuisine", p.name),
+    }
+}
+
+/// The "why this?" panel on a slot card.
+#[component]
+pub fn WhyPanel(why: Provenance) -> Element {
+    let cuisine = catalog().label(why.cuisine);
+    let pairing = match why.pairing {
+        Some((p, pairing, k)) if k != why.cuisine => {
+            format!("Fusion: {}", pairing_line(p, why.item, pairing, catalog().label(k)))
+        }
+        Some((p, pairing, _)) => pairing_line(p, why.item, pairing, cuisine),
+        None => format!("Listed under {cuisine}"),
+    };
+
+    rsx! {
+        div { class: "why",
+            p { class: "why__origin",
+                "{why.origin.describe()}"
+                if why.locked {
+                    " \u{00B7} locked"
+                }
+            }
+            p { class: "why__pairing", "{pairing}" }
+            if !why.reasons.is_empty() {
+                ul { class: "why__reasons",
+                    for r in why.reasons {
+                        li { "{r}" }
+                    }
+                }
+            }
+            if !why.scores.is_empty() {
+                ul { class: "score__lines",
+                    for line in why.scores {
+                        li { class: "score__line",
+                            span { class: "score__label", "{line.label}" }
+                            span {
+                                class: if line.points < 0 { "score__points score__points--minus" } else { "score__points" },
+                                "{line.points:+}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    use crate::components::pages::budget::Budget;
+    use crate::components::pages::history::History;
+    use crate::components::pages::meal_types::{LockState, Season};
+    use crate::components::pages::pantry::Pantry;
+    use crate::components::pages::preferences::Preferences;
+    use crate::components::pages::quality_engine::cascade_sampled;
+    use crate::components::pages::rules::MealRules;
+
+    fn item(id: &str) -> &'static Ingredient {
+        catalog().ingredients.iter().copied().find(|i| i.id.as_str() == id).unwrap_or_else(|| panic!("no {id}"))
+    }
+
+    /// A locked side sits next to whatever protein a reroll brings, so its
+    /// record has to name that one.
+    #[test]
+    fn locked_sides_follow_a_new_protein() {
+        let prefs = Preferences::default();
+        let (history, rules, pantry, budget) = (History::default(), MealRules::default(), Pantry::default(), Budget::default());
+        let env = GenEnv {
+            cuisine: Cuisine::American,
+            lock: LockState { veg1: true, ..LockState::default() },
+            has_veg2: false,
+            prefs: &prefs,
+            history: &history,
+            rules: &rules,
+            pantry: &pantry,
+            budget: &budget,
+            season: Season::default(),
+        };
+        let first = MealSelection { protein: Some(item("steak")), veg1: Some(item("broccoli")), ..MealSelection::default() };
+        let mut records = Provenances::generated(&first, env, None, 1);
+
+        let mut sel = MealSelection { protein: Some(item("chicken_breast")), ..first };
+        let (arch, sampled) = cascade_sampled(&mut sel, env, &mut SmallRng::seed_from_u64(7));
+        records.set(&sel, SlotKind::Protein, Origin::Rerolled, env, arch);
+        records.cascade(&sel, env, arch, sampled);
+
+        let broccoli = records.get(SlotKind::Veg1, item("broccoli")).expect("the locked side keeps a record");
+        assert_eq!(broccoli.origin, Origin::Kept);
+        assert_eq!(broccoli.pairing.map(|(p, ..)| p.id.as_str()), Some("chicken_breast"));
+
+        records.recuisine(&sel, None, GenEnv { cuisine: Cuisine::Mediterranean, ..env }, arch);
+        let broccoli = records.get(SlotKind::Veg1, item("broccoli")).expect("the locked side keeps a record");
+        assert_eq!(broccoli.cuisine, Cuisine::Mediterranean);
+    }
+}
@@ -0,0 +1,375 @@
+// Quality Engine — best-of-N side selection with a tunable score (spec §7)
+//
+// Tier 1 (hard) is enforced by `slot_candidates`: clashes and archetype
+// constraints never reach scoring. Tier 2 below only ranks what is left; a
+// missing pairing edge scores zero, never negative.
+
+use rand::Rng;
+
+use crate::content::{catalog, Archetype, Cuisine, Ingredient, IngredientId};
+
+use super::meal_types::{
+    choose_archetype, choose_weighted, clear_unlocked_sides, get_item, is_locked, pick_side, set_item, side_cuisine,
+    GenEnv, MealSelection, Pairing, Reserve, SlotKind,
+};
+
+/// Candidate plates sampled per generation.
+pub const CANDIDATE_N: usize = 40;
+
+/// Pairing edge between the protein and any side.
+pub const PROTEIN_SIDE_EDGE: i32 = 3;
+pub const STARCH_VEG1_EDGE: i32 = 3;
+pub const STARCH_VEG2_EDGE: i32 = 3;
+pub const VEG_VEG_EDGE: i32 = 2;
+/// A side that pairs with the protein only in another cuisine.
+pub const FUSION_EDGE: i32 = 1;
+/// Every item on a full plate pairs with every other.
+pub const ALL_CONNECTED_BONUS: i32 = 5;
+/// Each veg carrying one of the archetype's preferred tags.
+pub const ARCHETYPE_VEG_MATCH: i32 = 2;
+/// Two cold sides, even where the archetype allows them.
+pub const TWO_COLD_SIDES: i32 = -3;
+/// A side whose cuisine signal isn't one the archetype expects.
+pub const OFF_SIGNAL_SIDE: i32 = -3;
+/// Rerolls pick among candidates within this many points of the best, so
+/// pressing reroll twice doesn't just bounce between the top two items.
+pub const REROLL_SLACK: i32 = 3;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreLine {
+    pub label: String,
+    pub points: i32,
+    /// The items the rule is about.
+    pub items: Vec<IngredientId>,
+}
+
+/// A plate's Tier 2 score and the rules that produced it.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct ScoreBreakdown {
+    pub total: i32,
+    pub lines: Vec<ScoreLine>,
+}
+
+impl ScoreBreakdown {
+    fn add(&mut self, points: i32, label: String, items: &[&Ingredient]) {
+        self.total += points;
+        self.lines.push(ScoreLine { label, points, items: items.iter().map(|i| i.id).collect() });
+    }
+}
+
+/// Slots arrive in `SlotKind::ALL` order, so `a` is always the earlier one.
+const fn edge_weight(a: SlotKind, b: SlotKind) -> i32 {
+    match (a, b) {
+        (SlotKind::Starch, SlotKind::Veg1) => STARCH_VEG1_EDGE,
+        (SlotKind::Starch, SlotKind::Veg2) => STARCH_VEG2_EDGE,
+        (SlotKind::Veg1, SlotKind::Veg2) => VEG_VEG_EDGE,
+        _ => PROTEIN_SIDE_EDGE,
+    }
+}
+
+/// Cuisine signal tags the archetype asks for; empty means it doesn't care.
+fn expected_signals(a: &Archetype) -> Vec<&'static str> {
+    let c = &a.constraints;
+    c.starch_must_have_any
+        .iter()
+        .chain(c.veg_prefer_any)
+        .copied()
+        .filter(|t| t.ends_with("_signal"))
+        .collect()
+}
+
+pub fn score_meal(sel: &MealSelection, cuisine: Cuisine, arch: Option<&Archetype>) -> ScoreBreakdown {
+    let mut out = ScoreBreakdown::default();
+    let items: Vec<(SlotKind, &'static Ingredient)> =
+        SlotKind::ALL.into_iter().filter_map(|s| get_item(sel, s).map(|i| (s, i))).collect();
+
+    let mut connected = items.len() >= 3;
+    for (n, &(sa, a)) in items.iter().enumerate() {
+        for &(sb, b) in items.iter().skip(n + 1) {
+            if Pairing::of(a, b, cuisine).paired() {
+                out.add(edge_weight(sa, sb), format!("{} pairs with {}", a.name, b.name), &[a, b]);
+                continue;
+            }
+            connected = false;
+            let cross = side_cuisine(a, b, cuisine).filter(|&k| sa == SlotKind::Protein && k != cuisine);
+            if let Some(k) = cross {
+                let label = format!("{} pairs with {} in {} cooking", a.name, b.name, catalog().label(k));
+                out.add(FUSION_EDGE, label, &[a, b]);
+            }
+        }
+    }
+    if connected {
+        let all: Vec<&Ingredient> = items.iter().map(|(_, i)| *i).collect();
+        out.add(ALL_CONNECTED_BONUS, "Everything pairs with everything".to_string(), &all);
+    }
+
+    score_sides(&mut out, &items, arch);
+    out
+}
+
+/// Archetype fit, cold-side and cuisine-signal rules for the sides.
+fn score_sides(out: &mut ScoreBreakdown, items: &[(SlotKind, &'static Ingredient)], arch: Option<&Archetype>) {
+    let cat = catalog();
+    let expected = arch.map(expected_signals).unwrap_or_default();
+    let mut cold = Vec::new();
+    for &(slot, i) in items.iter().filter(|(s, _)| *s != SlotKind::Protein) {
+        let m = cat.meta(i.id);
+        let is_veg = slot != SlotKind::Starch;
+        if let Some(a) = arch.filter(|a| is_veg && a.prefers_veg(m)) {
+            out.add(ARCHETYPE_VEG_MATCH, format!("{} suits a {}", i.name, a.label), &[i]);
+        }
+        if is_veg && m.is_some_and(|m| m.has_tag("cold_side")) {
+            cold.push(i);
+        }
+        let off = m.is_some_and(|m| {
+            m.tags.iter().any(|t| t.ends_with("_signal") && !expected.is_empty() && !expected.contains(t))
+        });
+        if off {
+            out.add(OFF_SIGNAL_SIDE, format!("{} reads as another cuisine", i.name), &[i]);
+        }
+    }
+    if cold.len() >= 2 {
+        out.add(TWO_COLD_SIDES, "Two cold sides".to_string(), &cold);
+    }
+}
+
+/// Fills every unlocked side for the plate's protein with the best of
+/// `CANDIDATE_N` sampled combinations and returns the archetype it was
+/// built under. Sampling goes through `pick_side`, so Tier 1 holds for
+/// every candidate and a slot the archetype can't fill relaxes to plain
+/// pairings instead of coming back empty.
+pub fn cascade_from_protein(sel: &mut MealSelection, env: GenEnv, rng: &mut impl Rng) -> Option<&'static Archetype> {
+    cascade_sampled(sel, env, rng).0
+}
+
+/// `cascade_from_protein`, also returning how many different plates the
+/// samples came to; zero when every side was locked.
+pub fn cascade_sampled(sel: &mut MealSelection, env: GenEnv, rng: &mut impl Rng) -> (Option<&'static Archetype>, usize) {
+    clear_unlocked_sides(sel, env.lock);
+    let arch = choose_archetype(sel, env, rng);
+    let open: Vec<SlotKind> = [SlotKind::Starch, SlotKind::Veg1, SlotKind::Veg2]
+        .into_iter()
+        .filter(|&s| !is_locked(env.lock, s) && (s != SlotKind::Veg2 || env.has_veg2))
+        .collect();
+    if open.is_empty() {
+        return (arch, 0);
+    }
+
+    // The plate running least past the time limit, then the budget, wins;
+    // score decides among the rest
+    let mut best: Option<((i64, i64, i32), MealSelection)> = None;
+    let mut seen: Vec<MealSelection> = Vec::new();
+    for _ in 0..CANDIDATE_N {
+        let mut cand = *sel;
+        for &slot in &open {
+            let pick = pick_side(&cand, slot, env, arch, rng);
+            set_item(&mut cand, slot, pick);
+        }
+        if !seen.contains(&cand) {
+            seen.push(cand);
+        }
+        let (late, over) = env.overrun(&cand, arch, &Reserve::default());
+        let rank = (-i64::from(late), -i64::from(over), score_meal(&cand, env.cuisine, arch).total);
+        if best.is_none_or(|(b, _)| rank > b) {
+            best = Some((rank, cand));
+        }
+    }
+    if let Some((_, cand)) = best {
+        *sel = cand;
+    }
+    (arch, seen.len())
+}
+
+/// Re-optimizes one side with the rest of the plate fixed (spec §7.4):
+/// scores every candidate other than the current item and draws, by
+/// weight, among those within `REROLL_SLACK` of the best. Candidates past
+/// the time limit or budget are dropped first unless nothing else is left.
+pub fn reroll_side(
+    sel: &MealSelection,
+    slot: SlotKind,
+    env: GenEnv,
+    arch: Option<&Archetype>,
+    rng: &mut impl Rng,
+) -> Option<&'static Ingredient> {
+    let list = env.pool(slot);
+    let mut pool = env.candidates(sel, slot, &list, arch);
+    if pool.is_empty() {
+        pool = env.candidates(sel, slot, &list, None);
+    }
+    let mut pool = env.within_limits(pool, sel, slot, arch);
+    let current = get_item(sel, slot);
+    if pool.iter().any(|&i| Some(i) != current) {
+        pool.retain(|&i| Some(i) != current);
+    }
+    let mut rest = *sel;
+    set_item(&mut rest, slot, None);
+
+    let scored: Vec<(i32, &'static Ingredient)> = pool
+        .into_iter()
+        .map(|i| {
+            let mut cand = *sel;
+            set_item(&mut cand, slot, Some(i));
+            (score_meal(&cand, env.cuisine, arch).total, i)
+        })
+        .collect();
+    let top = scored.iter().map(|(s, _)| *s).max()?;
+    let near: Vec<&'static Ingredient> =
+        scored.into_iter().filter(|(s, _)| *s >= top - REROLL_SLACK).map(|(_, i)| i).collect();
+    choose_weighted(&near, &rest, env, rng)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::components::pages::budget::Budget;
+    use crate::components::pages::history::History;
+    use crate::components::pages::meal_types::{choose_archetype, LockState, Season};
+    use crate::components::pages::pantry::Pantry;
+    use crate::components::pages::preferences::Preferences;
+    use crate::components::pages::rules::MealRules;
+    use crate::content::archetypes_for;
+
+    fn item(id: &str) -> &'static Ingredient {
+        catalog().ingredients.iter().copied().find(|i| i.id.as_str() == id).unwrap_or_else(|| panic!("no {id}"))
+    }
+
+    fn arch(cuisine: Cuisine, id: &str) -> &'static Archetype {
+        archetypes_for(cuisine).find(|a| a.id == id).unwrap_or_else(|| panic!("no {id}"))
+    }
+
+    fn plate(protein: &str, starch: &str, veg1: &str, veg2: Option<&str>) -> MealSelection {
+        MealSelection { protein: Some(item(protein)), starch: Some(item(starch)), veg1: Some(item(veg1)), veg2: veg2.map(item) }
+    }
+
+    /// The score as (label, points) pairs, in the order they were added.
+    fn lines(sel: &MealSelection, cuisine: Cuisine, arch: Option<&Archetype>) -> (i32, Vec<(String, i32)>) {
+        let s = score_meal(sel, cuisine, arch);
+        (s.total, s.lines.into_iter().map(|l| (l.label, l.points)).collect())
+    }
+
+    /// Runs `f` against the built-in catalog and default household settings.
+    fn with_env<T>(cuisine: Cuisine, lock: LockState, f: impl FnOnce(GenEnv) -> T) -> T {
+        let prefs = Preferences::default();
+        let (history, rules, pantry, budget) = (History::default(), MealRules::default(), Pantry::default(), Budget::default());
+        f(GenEnv {
+            cuisine,
+            lock,
+            has_veg2: true,
+            prefs: &prefs,
+            history: &history,
+            rules: &rules,
+            pantry: &pantry,
+            budget: &budget,
+            season: Season::default(),
+        })
+    }
+
+    #[test]
+    fn a_fully_paired_plate_earns_every_edge_and_the_bonus() {
+        let sel = plate("steak", "mashed_potatoes", "green_beans", None);
+        let (total, lines) = lines(&sel, Cuisine::American, None);
+        let want = [
+            ("Steak pairs with Mashed Potatoes", PROTEIN_SIDE_EDGE),
+            ("Steak pairs with Green Beans", PROTEIN_SIDE_EDGE),
+            ("Mashed Potatoes pairs with Green Beans", STARCH_VEG1_EDGE),
+            ("Everything pairs with everything", ALL_CONNECTED_BONUS),
+        ];
+        assert_eq!(lines, want.map(|(l, p)| (l.to_string(), p)));
+        assert_eq!(total, 14);
+    }
+
+    #[test]
+    fn a_missing_edge_loses_the_bonus_and_archetype_vegs_score() {
+        let sel = plate("steak", "mashed_potatoes", "green_beans", Some("caesar_salad"));
+        let (total, lines) = lines(&sel, Cuisine::American, Some(arch(Cuisine::American, "steakhouse")));
+        let want = [
+            ("Steak pairs with Mashed Potatoes", PROTEIN_SIDE_EDGE),
+            ("Steak pairs with Green Beans", PROTEIN_SIDE_EDGE),
+            ("Steak pairs with Caesar Salad", PROTEIN_SIDE_EDGE),
+            ("Mashed Potatoes pairs with Green Beans", STARCH_VEG1_EDGE),
+            ("Green Beans suits a Steakhouse", ARCHETYPE_VEG_MATCH),
+            ("Caesar Salad suits a Steakhouse", ARCHETYPE_VEG_MATCH),
+        ];
+        assert_eq!(lines, want.map(|(l, p)| (l.to_string(), p)));
+        assert_eq!(total, 16);
+    }
+
+    #[test]
+    fn two_cold_sides_cost_points() {
+        let sel = plate("chicken_breast", "garlic_bread", "caesar_salad", Some("garden_salad"));
+        let (total, lines) = lines(&sel, Cuisine::American, None);
+        assert_eq!(lines.last(), Some(&("Two cold sides".to_string(), TWO_COLD_SIDES)));
+        assert_eq!(total, 4 * PROTEIN_SIDE_EDGE + TWO_COLD_SIDES);
+    }
+
+    #[test]
+    fn off_signal_sides_and_fusion_edges_score_their_lines() {
+        let sel = plate("chicken_breast", "fried_rice", "collard_greens", None);
+        let (total, lines) = lines(&sel, Cuisine::Asian, Some(arch(Cuisine::Asian, "fried_rice_bowl")));
+        let want = [
+            ("Chicken Breast pairs with Fried Rice", PROTEIN_SIDE_EDGE),
+            ("Chicken Breast pairs with Collard Greens in American cooking", FUSION_EDGE),
+            ("Collard Greens suits a Fried Rice Bowl", ARCHETYPE_VEG_MATCH),
+            ("Collard Greens reads as another cuisine", OFF_SIGNAL_SIDE),
+        ];
+        assert_eq!(lines, want.map(|(l, p)| (l.to_string(), p)));
+        assert_eq!(total, 3);
+    }
+
+    /// Replays the same samples from a cloned RNG: the plate kept is the
+    /// best-scoring one of the `CANDIDATE_N`.
+    #[test]
+    fn the_cascade_keeps_the_best_sample() {
+        with_env(Cuisine::American, LockState::default(), |env| {
+            for seed in 0..10 {
+                let base = MealSelection { protein: Some(item("chicken_breast")), ..MealSelection::default() };
+                let mut rng = SmallRng::seed_from_u64(seed);
+                let mut replay = rng.clone();
+                let mut sel = base;
+                let (arch, sampled) = cascade_sampled(&mut sel, env, &mut rng);
+                assert!((1..=CANDIDATE_N).contains(&sampled));
+
+                assert_eq!(choose_archetype(&base, env, &mut replay), arch);
+                let mut scores = Vec::new();
+                for _ in 0..CANDIDATE_N {
+                    let mut cand = base;
+                    for slot in [SlotKind::Starch, SlotKind::Veg1, SlotKind::Veg2] {
+                        let pick = pick_side(&cand, slot, env, arch, &mut replay);
+                        set_item(&mut cand, slot, pick);
+                    }
+                    scores.push((score_meal(&cand, env.cuisine, arch).total, cand));
+                }
+                let best = scores.iter().map(|(s, _)| *s).max();
+                assert_eq!(Some(score_meal(&sel, env.cuisine, arch).total), best, "seed {seed}");
+                assert!(scores.iter().any(|(_, c)| *c == sel), "seed {seed}");
+            }
+        });
+    }
+
+    #[test]
+    fn rerolls_draw_within_the_slack_of_the_best() {
+        let sel = plate("steak", "mashed_potatoes", "green_beans", None);
+        let steakhouse = arch(Cuisine::American, "steakhouse");
+        with_env(Cuisine::American, LockState::default(), |env| {
+            let list = env.pool(SlotKind::Veg1);
+            let scores: Vec<(i32, &'static Ingredient)> = env
+                .candidates(&sel, SlotKind::Veg1, &list, Some(steakhouse))
+                .into_iter()
+                .filter(|&i| Some(i) != sel.veg1)
+                .map(|i| (score_meal(&MealSelection { veg1: Some(i), ..sel }, env.cuisine, Some(steakhouse)).total, i))
+                .collect();
+            let top = scores.iter().map(|(s, _)| *s).max().expect("other vegs");
+            assert!(scores.iter().any(|(s, _)| *s < top - REROLL_SLACK), "some vegs fall outside the slack");
+            for seed in 0..50 {
+                let pick = reroll_side(&sel, SlotKind::Veg1, env, Some(steakhouse), &mut SmallRng::seed_from_u64(seed));
+                let pick = pick.expect("a veg");
+                assert_ne!(Some(pick), sel.veg1);
+                let score = scores.iter().find(|(_, i)| *i == pick).map(|(s, _)| *s).expect("a candidate");
+                assert!(score >= top - REROLL_SLACK, "{} scored {score}, best {top}", pick.id.as_str());
+            }
+        });
+    }
+}
@@ -0,0 +1,393 @@
+// House Rules — standing household constraints, written one per line
+//
+//   tuesday: latin                       weekday to cuisine
+//   weekdays: 30 min                     weekday to time budget
+//   steak/ground_beef at most 2 per week frequency cap (rolling seven days)
+//   no pasta on weekdays                 ban, optionally on some days only
+//
+// Items are catalog IDs or names; `#tag` matches every item carrying a meta
+// tag, and `//` starts a comment line. The household's text is what gets
+// saved. It is parsed against the active catalog whenever it's read, so a
+// rule naming a pack item starts working once that pack loads.
+
+use dioxus::prelude::*;
+
+use crate::content::{catalog, Archetype, Cuisine, Ingredient, IngredientId};
+
+use super::history::History;
+use super::meal_types::MealSelection;
+use super::storage;
+use super::timing::meal_time;
+use super::week_plan::DAY_NAMES;
+
+const STORAGE_KEY: &str = "mealgen.rules";
+
+/// The rules text exactly as the household typed it.
+pub static RULES: GlobalSignal<HouseRules> = Signal::global(HouseRules::load);
+
+/// Days of the week a rule covers, Monday as bit 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DaySet(u8);
+
+impl DaySet {
+    pub const ALL: DaySet = DaySet(0b111_1111);
+    pub const WEEKDAYS: DaySet = DaySet(0b001_1111);
+    pub const WEEKENDS: DaySet = DaySet(0b110_0000);
+
+    pub const fn contains(self, day: usize) -> bool {
+        day < 7 && self.0 & (1 << day) != 0
+    }
+
+    const fn overlaps(self, other: DaySet) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Target {
+    Item(IngredientId),
+    Tag(String),
+}
+
+impl Target {
+    pub fn matches(&self, i: &Ingredient) -> bool {
+        match self {
+            Target::Item(id) => i.id == *id,
+            Target::Tag(tag) => catalog().meta(i.id).is_some_and(|m| m.has_tag(tag)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Rule {
+    Cuisine { days: DaySet, cuisine: Cuisine },
+    TimeBudget { days: DaySet, minutes: u32 },
+    Cap { targets: Vec<Target>, per_week: usize },
+    Ban { targets: Vec<Target>, days: DaySet },
+}
+
+/// A rule and the line it was written as, for explanations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParsedRule {
+    pub text: String,
+    pub rule: Rule,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuleError {
+    /// 1-based, counting blank and comment lines.
+    pub line: usize,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct RuleSet {
+    pub rules: Vec<ParsedRule>,
+    pub errors: Vec<RuleError>,
+}
+
+/// The household's rules as typed.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct HouseRules {
+    pub source: String,
+}
+
+impl HouseRules {
+    pub fn parsed(&self) -> RuleSet {
+        parse_rules(&self.source)
+    }
+
+    pub fn load() -> Self {
+        let source = storage::read(STORAGE_KEY).unwrap_or_default();
+        HouseRules { source }
+    }
+
+    pub fn save(&self) {
+        storage::write(STORAGE_KEY, &self.source);
+    }
+}
+
+/// Parses every non-blank line; `//` starts a comment line. Lines that fail
+/// are reported and left out, the rest still apply.
+pub fn parse_rules(src: &str) -> RuleSet {
+    let mut out = RuleSet::default();
+    for (n, line) in src.lines().enumerate() {
+        let text = line.trim();
+        if text.is_empty() || text.starts_with("//") {
+            continue;
+        }
+        let parsed = parse_rule(text).and_then(|rule| check_overlap(&out.rules, &rule).map(|()| rule));
+        match parsed {
+            Ok(rule) => out.rules.push(ParsedRule { text: text.to_string(), rule }),
+            Err(message) => out.errors.push(RuleError { line: n + 1, message }),
+        }
+    }
+    out
+}
+
+pub fn parse_rule(line: &str) -> Result<Rule, String> {
+    let text = line.trim().to_lowercase();
+    if let Some(rest) = text.strip_prefix("no ") {
+        return parse_ban(rest);
+    }
+    if let Some((items, limit)) = text.split_once(" at most ") {
+        return parse_cap(items, limit);
+    }
+    if let Some((days, what)) = text.split_once(':') {
+        return parse_day_rule(days, what);
+    }
+    Err("expected \"<day>: <cuisine>\", \"<day>: <N> min\", \"<items> at most <N> per week\" or \"no <items> on <days>\""
+        .to_string())
+}
+
+fn parse_ban(rest: &str) -> Result<Rule, String> {
+    let (items, days) = match rest.rsplit_once(" on ") {
+        Some((items, days)) => (items, parse_days(days)?),
+        None => (rest, DaySet::ALL),
+    };
+    Ok(Rule::Ban { targets: parse_targets(items)?, days })
+}
+
+fn parse_cap(items: &str, limit: &str) -> Result<Rule, String> {
+    let mut words = limit.split_whitespace();
+    let per_week = words
+        .next()
+        .and_then(|w| w.parse::<usize>().ok())
+        .ok_or_else(|| "expected a number after \"at most\"".to_string())?;
+    let rest: Vec<&str> = words.filter(|w| !matches!(*w, "times" | "x")).collect();
+    if !matches!(rest.as_slice(), ["per" | "a", "week"]) {
+        return Err("caps are counted per week, e.g. \"at most 2 per week\"".to_string());
+    }
+    Ok(Rule::Cap { targets: parse_targets(items)?, per_week })
+}
+
+fn parse_day_rule(days: &str, what: &str) -> Result<Rule, String> {
+    let days = parse_days(days)?;
+    let what = what.trim();
+    if let Some(minutes) = parse_minutes(what) {
+        return Ok(Rule::TimeBudget { days, minutes });
+    }
+    find_cuisine(what)
+        .map(|cuisine| Rule::Cuisine { days, cuisine })
+        .ok_or_else(|| format!("unknown cuisine \"{what}\""))
+}
+
+/// "30 min", "30min", "45 minutes".
+fn parse_minutes(s: &str) -> Option<u32> {
+    let digits: String = s.chars().take_while(char::is_ascii_digit).collect();
+    let unit = s.strip_prefix(digits.as_str())?.trim();
+    matches!(unit, "min" | "mins" | "minute" | "minutes").then(|| digits.parse().ok()).flatten()
+}
+
+/// Matches a cuisine ID or any part of its label ("mexican" for
+/// "Latin / Mexican").
+fn find_cuisine(s: &str) -> Option<Cuisine> {
+    catalog().cuisine_list().into_iter().find(|&c| {
+        c.id() == s || catalog().label(c).to_lowercase().split('/').any(|part| part.trim() == s)
+    })
+}
+
+fn parse_days(s: &str) -> Result<DaySet, String> {
+    let mut set = DaySet(0);
+    for token in s.split(['/', ',']).map(str::trim) {
+        let day = match token.trim_end_matches('s') {
+            "weekday" => DaySet::WEEKDAYS,
+            "weekend" => DaySet::WEEKENDS,
+            "every day" | "everyday" | "daily" => DaySet::ALL,
+            t if t.len() >= 3 => DAY_NAMES
+                .iter()
+                .position(|d| d.to_lowercase().starts_with(t))
+                .map(|d| DaySet(1 << d))
+                .ok_or_else(|| format!("unknown day \"{token}\""))?,
+            _ => return Err(format!("unknown day \"{token}\"")),
+        };
+        set.0 |= day.0;
+    }
+    Ok(set)
+}
+
+fn parse_targets(s: &str) -> Result<Vec<Target>, String> {
+    s.split(['/', ',']).map(str::trim).map(parse_target).collect()
+}
+
+fn parse_target(s: &str) -> Result<Target, String> {
+    let cat = catalog();
+    if let Some(tag) = s.strip_prefix('#') {
+        return if cat.meta.iter().any(|m| m.has_tag(tag)) {
+            Ok(Target::Tag(tag.to_string()))
+        } else {
+            Err(format!("no ingredient is tagged \"{tag}\""))
+        };
+    }
+    let key = s.replace([' ', '-'], "_");
+    cat.ingredients
+        .iter()
+        .find(|i| i.id.as_str() == key || i.name.to_lowercase() == s)
+        .map(|i| Target::Item(i.id))
+        .ok_or_else(|| format!("unknown ingredient \"{s}\""))
+}
+
+/// Two cuisine rules (or two time budgets) for the same day can't both hold.
+fn check_overlap(rules: &[ParsedRule], rule: &Rule) -> Result<(), String> {
+    let clash = rules.iter().find(|p| match (&p.rule, rule) {
+        (Rule::Cuisine { days: a, .. }, Rule::Cuisine { days: b, .. })
+        | (Rule::TimeBudget { days: a, .. }, Rule::TimeBudget { days: b, .. }) => a.overlaps(*b),
+        _ => false,
+    });
+    match clash {
+        Some(p) => Err(format!("overlaps \"{}\"", p.text)),
+        None => Ok(()),
+    }
+}
+
+/// What the rules ask of one dinner, given the other meals in its week.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct MealRules {
+    pub cuisine: Option<(Cuisine, String)>,
+    pub minutes: Option<(u32, String)>,
+    /// Items kept off the plate, with the rule that did it.
+    pub excluded: Vec<(IngredientId, String)>,
+}
+
+impl RuleSet {
+    /// Tonight's rules, capped against the household's last six dinners.
+    pub fn tonight(&self, history: &History) -> MealRules {
+        self.for_meal(history.weekday(), &history.this_week())
+    }
+
+    /// Resolves the rules for a dinner on `weekday` (Monday is 0).
+    /// `others` are the rest of its week's meals, for frequency caps.
+    pub fn for_meal(&self, weekday: usize, others: &[Vec<&'static Ingredient>]) -> MealRules {
+        let mut out = MealRules::default();
+        for p in &self.rules {
+            let targets = match &p.rule {
+                Rule::Cuisine { days, cuisine } if days.contains(weekday) => {
+                    out.cuisine = Some((*cuisine, p.text.clone()));
+                    continue;
+                }
+                Rule::TimeBudget { days, minutes } if days.contains(weekday) => {
+                    out.minutes = Some((*minutes, p.text.clone()));
+                    continue;
+                }
+                Rule::Ban { targets, days } if days.contains(weekday) => targets,
+                Rule::Cap { targets, per_week } if capped(targets, *per_week, others) => targets,
+                _ => continue,
+            };
+            // The first rule to exclude an item is the one explained
+            let hit: Vec<(IngredientId, String)> = catalog()
+                .ingredients
+                .iter()
+                .filter(|i| targets.iter().any(|t| t.matches(i)) && out.excluded_by(i).is_none())
+                .map(|i| (i.id, p.text.clone()))
+                .collect();
+            out.excluded.extend(hit);
+        }
+        out
+    }
+}
+
+fn capped(targets: &[Target], per_week: usize, others: &[Vec<&'static Ingredient>]) -> bool {
+    let hits = others.iter().filter(|meal| meal.iter().any(|i| targets.iter().any(|t| t.matches(i))));
+    hits.count() >= per_week
+}
+
+impl MealRules {
+    pub fn excluded_by(&self, i: &Ingredient) -> Option<&str> {
+        self.excluded.iter().find(|(id, _)| *id == i.id).map(|(_, text)| text.as_str())
+    }
+
+    pub fn allowed(&self, list: Vec<&'static Ingredient>) -> Vec<&'static Ingredient> {
+        list.into_iter().filter(|i| self.excluded_by(i).is_none()).collect()
+    }
+
+    /// Rules the plate breaks, in words. Locks and manual picks can put a
+    /// plate outside the rules; it's explained rather than refused.
+    pub fn violations(&self, cuisine: Cuisine, sel: &MealSelection, arch: Option<&Archetype>) -> Vec<String> {
+        let mut out = Vec::new();
+        if let Some((want, text)) = self.cuisine.as_ref().filter(|(c, _)| *c != cuisine) {
+            out.push(format!(
+                "\u{201C}{text}\u{201D} asks for {}, not {}",
+                catalog().label(*want),
+                catalog().label(cuisine)
+            ));
+        }
+        if let Some((max, text)) = &self.minutes {
+            let total = meal_time(sel, arch).minutes.total;
+            if total > *max {
+                out.push(format!("About {total} min breaks \u{201C}{text}\u{201D}"));
+            }
+        }
+        for i in [sel.protein, sel.starch, sel.veg1, sel.veg2].into_iter().flatten() {
+            if let Some(text) = self.excluded_by(i) {
+                out.push(format!("{} breaks \u{201C}{text}\u{201D}", i.name));
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: &str) -> &'static Ingredient {
+        catalog().ingredients.iter().copied().find(|i| i.id.as_str() == id).unwrap_or_else(|| panic!("no {id}"))
+    }
+
+    fn excluded(rules: &MealRules) -> Vec<&'static str> {
+        rules.excluded.iter().map(|(id, _)| id.as_str()).collect()
+    }
+
+    /// The examples in the header comment must keep parsing.
+    #[test]
+    fn header_examples_parse() {
+        let set = parse_rules(
+            "tuesday: latin\nweekdays: 30 min\nsteak/ground_beef at most 2 per week\nno pasta on weekdays\n// taco night\nno #protein_seafood on sat/sun",
+        );
+        assert!(set.errors.is_empty(), "{:?}", set.errors);
+        let rules: Vec<&Rule> = set.rules.iter().map(|p| &p.rule).collect();
+        let [cuisine, budget, cap, ban, tagged] = rules.as_slice() else { panic!("expected 5 rules, got {rules:?}") };
+        assert!(matches!(cuisine, Rule::Cuisine { days, cuisine: Cuisine::Latin } if *days == DaySet(0b10)));
+        assert_eq!(*budget, &Rule::TimeBudget { days: DaySet::WEEKDAYS, minutes: 30 });
+        assert!(matches!(cap, Rule::Cap { targets, per_week: 2 } if targets.len() == 2));
+        assert!(matches!(ban, Rule::Ban { days, .. } if *days == DaySet::WEEKDAYS));
+        let seafood = vec![Target::Tag("protein_seafood".into())];
+        assert_eq!(*tagged, &Rule::Ban { targets: seafood, days: DaySet::WEEKENDS });
+    }
+
+    #[test]
+    fn overlapping_cuisine_rules_are_refused() {
+        let set = parse_rules("tue: latin\nweekdays: asian");
+        assert_eq!(set.rules.len(), 1);
+        assert_eq!(set.errors.iter().map(|e| e.line).collect::<Vec<_>>(), vec![2]);
+    }
+
+    #[test]
+    fn unknown_items_are_errors_on_their_line() {
+        let set = parse_rules("// comment\n\nno dragonfruit");
+        assert!(set.rules.is_empty());
+        assert_eq!(set.errors.iter().map(|e| e.line).collect::<Vec<_>>(), vec![3]);
+    }
+
+    #[test]
+    fn bans_and_budgets_follow_the_day() {
+        let set = parse_rules("no pasta on weekdays\nweekdays: 30 min\nfriday: asian");
+        let monday = set.for_meal(0, &[]);
+        assert_eq!(excluded(&monday), vec!["pasta"]);
+        assert_eq!(monday.minutes.map(|(m, _)| m), Some(30));
+        assert_eq!(monday.cuisine, None);
+        let saturday = set.for_meal(5, &[]);
+        assert!(saturday.excluded.is_empty());
+        assert_eq!(saturday.minutes, None);
+        assert_eq!(set.for_meal(4, &[]).cuisine.map(|(c, _)| c), Some(Cuisine::Asian));
+    }
+
+    #[test]
+    fn caps_count_the_rest_of_the_week() {
+        let set = parse_rules("steak at most 2 per week");
+        let once = vec![vec![item("steak"), item("fries")]];
+        assert!(set.for_meal(0, &once).excluded.is_empty());
+        let twice = vec![vec![item("steak")], vec![item("steak"), item("broccoli")]];
+        assert_eq!(excluded(&set.for_meal(0, &twice)), vec!["steak"]);
+    }
+}
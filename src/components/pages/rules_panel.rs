@@ -0,0 +1,77 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+
+use crate::content::catalog;
+
+use super::history::History;
+use super::rules::{parse_rules, HouseRules};
+use super::week_plan::DAY_NAMES;
+
+const PLACEHOLDER: &str = "tuesday: latin\nweekdays: 30 min\nsteak/ground_beef at most 2 per week\nno pasta on weekdays";
+
+/// Editor for the household's standing rules. Every line is checked as it's
+/// typed; saving is held back until the whole text parses.
+#[component]
+pub fn RulesPanel(rules: Signal<HouseRules>, history: Signal<History>) -> Element {
+    let mut draft = use_signal(|| rules.read().source.clone());
+    let parsed = parse_rules(&draft.read());
+    let saved = *draft.read() == rules.read().source;
+    let count = parsed.rules.len();
+    let valid = parsed.errors.is_empty();
+
+    let tonight = rules.read().parsed().tonight(&history.read());
+    let day = DAY_NAMES.get(history.read().weekday()).copied().unwrap_or_default();
+    let mut summary: Vec<String> = Vec::new();
+    if let Some((c, _)) = tonight.cuisine {
+        summary.push(catalog().label(c).to_string());
+    }
+    if let Some((m, _)) = tonight.minutes {
+        summary.push(format!("{m} min"));
+    }
+    match tonight.excluded.len() {
+        0 => {}
+        1 => summary.push("1 item off the menu".to_string()),
+        n => summary.push(format!("{n} items off the menu")),
+    }
+    let summary = summary.join(" \u{00B7} ");
+
+    rsx! {
+        div { class: "household rules",
+            span { class: "household__title", "House rules" }
+            textarea {
+                class: "rules__input",
+                rows: 5,
+                spellcheck: false,
+                placeholder: PLACEHOLDER,
+                value: "{draft}",
+                oninput: move |e| draft.set(e.value()),
+            }
+            if !valid {
+                ul { class: "rules__errors",
+                    for e in parsed.errors {
+                        li { "Line {e.line}: {e.message}" }
+                    }
+                }
+            }
+            div { class: "household__row",
+                button {
+                    class: "btn rules__save",
+                    disabled: saved || !valid,
+                    onclick: move |_| {
+                        let mut w = rules.write();
+                        w.source.clone_from(&draft.read());
+                        w.save();
+                    },
+                    if saved { "Saved" } else { "Save rules" }
+                }
+                span { class: "rules__count",
+                    if count == 1 { "1 rule" } else { "{count} rules" }
+                }
+            }
+            if !summary.is_empty() {
+                p { class: "rules__tonight", "{day}: {summary}" }
+            }
+        }
+    }
+}
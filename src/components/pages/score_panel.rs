@@ -0,0 +1,34 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+
+use crate::content::{Archetype, Cuisine};
+
+use super::meal_types::MealSelection;
+use super::quality_engine::score_meal;
+
+/// Collapsible "why this plate" breakdown of the quality score.
+#[component]
+pub fn ScorePanel(selection: MealSelection, cuisine: Cuisine, archetype: Option<&'static Archetype>) -> Element {
+    let score = score_meal(&selection, cuisine, archetype);
+    if score.lines.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        details { class: "score",
+            summary { class: "score__summary", "Plate score · {score.total}" }
+            ul { class: "score__lines",
+                for line in score.lines {
+                    li { class: "score__line",
+                        span { class: "score__label", "{line.label}" }
+                        span {
+                            class: if line.points < 0 { "score__points score__points--minus" } else { "score__points" },
+                            "{line.points:+}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,35 @@
+// Local Storage — household state that outlives the tab
+//
+// Every saved setting is one key in the browser's local storage, written as
+// JSON (or plain text for the rules, which are saved as typed). Reads that
+// find nothing or can't parse fall back to the caller's default, so a
+// private window or a format change starts fresh rather than failing.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+pub fn read(key: &str) -> Option<String> {
+    local_storage()?.get_item(key).ok().flatten()
+}
+
+pub fn write(key: &str, value: &str) {
+    if let Some(s) = local_storage() {
+        let _ = s.set_item(key, value);
+    }
+}
+
+/// The value saved under `key`, or `None` when there's none or it no
+/// longer parses.
+pub fn read_json<T: DeserializeOwned>(key: &str) -> Option<T> {
+    serde_json::from_str(&read(key)?).ok()
+}
+
+pub fn write_json<T: Serialize>(key: &str, value: &T) {
+    if let Ok(json) = serde_json::to_string(value) {
+        write(key, &json);
+    }
+}
@@ -0,0 +1,77 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+
+use crate::content::{catalog, Archetype, Ingredient, Minutes};
+
+use super::meal_types::MealSelection;
+
+/// Choices offered for the household's "done in" limit.
+pub const DONE_IN_CHOICES: [u32; 4] = [20, 30, 45, 60];
+
+/// Time for a plate cooked in parallel, and the items with no time to count.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct MealTime {
+    pub minutes: Minutes,
+    pub untimed: Vec<&'static str>,
+}
+
+fn item_minutes(i: &Ingredient, arch: Option<&Archetype>) -> Option<Minutes> {
+    catalog().minutes(i.id, arch.map(|a| a.id))
+}
+
+pub fn meal_time(sel: &MealSelection, arch: Option<&Archetype>) -> MealTime {
+    let mut timed = Vec::new();
+    let mut untimed = Vec::new();
+    for i in [sel.protein, sel.starch, sel.veg1, sel.veg2].into_iter().flatten() {
+        match item_minutes(i, arch) {
+            Some(m) => timed.push(m),
+            None => untimed.push(i.name),
+        }
+    }
+    MealTime { minutes: Minutes::plate(timed), untimed }
+}
+
+/// Items that finish within `limit` on their own. Untimed items pass.
+pub fn quick_items(list: Vec<&'static Ingredient>, limit: Option<u32>) -> Vec<&'static Ingredient> {
+    let Some(max) = limit else { return list };
+    list.into_iter().filter(|i| item_minutes(i, None).is_none_or(|m| m.total <= max)).collect()
+}
+
+/// The least hands-on item in `pool`, as `arch` cooks it.
+pub fn easiest(pool: &[&'static Ingredient], arch: Option<&Archetype>) -> Minutes {
+    pool.iter().filter_map(|i| item_minutes(i, arch)).min_by_key(|m| m.hands_on).unwrap_or_default()
+}
+
+/// How far the plate, plus `extra` items still to come, runs past `limit`;
+/// zero when it fits or there's none.
+pub fn minutes_over(sel: &MealSelection, arch: Option<&Archetype>, extra: &[Minutes], limit: Option<u32>) -> u32 {
+    let Some(max) = limit else { return 0 };
+    let items = [sel.protein, sel.starch, sel.veg1, sel.veg2].into_iter().flatten().filter_map(|i| item_minutes(i, arch));
+    Minutes::plate(items.chain(extra.iter().copied())).total.saturating_sub(max)
+}
+
+/// Estimated time shown with the meal description.
+#[component]
+pub fn TimeLine(selection: MealSelection, archetype: Option<&'static Archetype>, limit: Option<u32>) -> Element {
+    let time = meal_time(&selection, archetype);
+    if time.minutes.total == 0 {
+        return rsx! {};
+    }
+    let Minutes { hands_on, total } = time.minutes;
+    let over = limit.filter(|&max| total > max);
+
+    rsx! {
+        p { class: "meal-time",
+            "Ready in about "
+            span { class: "meal-time__total", "{total} min" }
+            " \u{00B7} {hands_on} min hands-on"
+            if let Some(max) = over {
+                span { class: "meal-time__over", " \u{00B7} over your {max} min limit" }
+            }
+            if !time.untimed.is_empty() {
+                span { " \u{00B7} no time for {time.untimed.join(\", \")}" }
+            }
+        }
+    }
+}
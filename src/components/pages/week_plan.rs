@@ -0,0 +1,769 @@
+// Week Plan — seven dinners generated together under week-level rules
+//
+// Each day is built like a single plate (seeded protein pick, best-of-N
+// sides) once its cuisine and protein are chosen against the neighbouring
+// days. When a day can't meet every rule, they relax one at a time, least
+// important first: a batch day's lean towards proteins with leftovers, the
+// cuisine cap, cuisine repeats, fish nights, protein repeats. Slots locked
+// on an unlocked day are kept, and the day is built around them like a
+// plate with locked items.
+// Cuisines are drawn with the history's deficit weights, as for a single
+// plate, among those the day's rules leave open.
+// House rules sit above all of them: a day's cuisine rule narrows its
+// cuisines, and bans and weekly caps (counted within this week) narrow its
+// pools.
+//
+// Leftover nights pair a batch day with the day after it. The batch day
+// leans towards an item with a transform (leftovers.rs) and is cooked
+// double; the next day is built around what the batch becomes, so it may
+// repeat the protein on purpose. Neither day of a pair is a fish night.
+
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::content::{
+    catalog, meal_amount, transforms_from, Amount, Archetype, Category, Cuisine, Ingredient, IngredientId,
+    Transform,
+};
+
+use super::generator_logic::derived_rng;
+use super::history::History;
+use super::meal_types::{
+    choose_weighted, clear_unlocked_sides, cuisine_conflicts, in_cuisine, pairs_with_protein, pairs_with_sides,
+    refit_archetype, set_item, slot_candidates, GenEnv, Household, LockState, MealSelection, Season, SlotKind,
+    SlotOption,
+};
+use super::budget::Budget;
+use super::pantry::Pantry;
+use super::preferences::Preferences;
+use super::quality_engine::{cascade_from_protein, reroll_side};
+use super::rules::{MealRules, RuleSet};
+
+pub const DAY_NAMES: [&str; 7] = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+
+// Relaxation steps in the header comment's order; a day at level N has
+// relaxed every step up to N, and level 0 relaxes nothing.
+const RELAX_BATCH: u8 = 1;
+const RELAX_CUISINE_CAP: u8 = 2;
+const RELAX_CUISINE_REPEATS: u8 = 3;
+const RELAX_FISH: u8 = 4;
+const RELAX_PROTEIN_REPEATS: u8 = 5;
+/// Levels tried per day: nothing relaxed, then one more step at a time.
+const RELAX_LEVELS: u8 = RELAX_PROTEIN_REPEATS + 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct DayPlan {
+    pub cuisine: Option<Cuisine>,
+    pub selection: MealSelection,
+    pub archetype: Option<&'static Archetype>,
+    pub locked: bool,
+    /// Slots kept when the day itself is rebuilt.
+    pub locks: LockState,
+    /// Salts the day's reroll seed so each press gives a new plate.
+    pub rerolls: u32,
+    /// Set when the day is built from the previous day's double batch.
+    pub leftover: Option<&'static Transform>,
+}
+
+impl DayPlan {
+    /// The day emptied down to its locked slots, ready to be rebuilt.
+    fn cleared(&self) -> DayPlan {
+        let mut selection = self.selection;
+        clear_unlocked_sides(&mut selection, self.locks);
+        if !self.locks.protein {
+            selection.protein = None;
+        }
+        DayPlan { selection, locks: self.locks, rerolls: self.rerolls, ..DayPlan::default() }
+    }
+
+    /// The leftover note, while the plate still has what the batch became.
+    fn leftover_on(&self, sel: &MealSelection) -> Option<&'static Transform> {
+        self.leftover.filter(|t| plate_items(sel).iter().any(|i| i.id == t.into))
+    }
+}
+
+pub type Week = [DayPlan; 7];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WeekRules {
+    pub fish_nights: usize,
+    pub has_veg2: bool,
+    /// Days built from the day before's double batch.
+    pub leftover_nights: usize,
+}
+
+impl Default for WeekRules {
+    fn default() -> Self {
+        WeekRules { fish_nights: 1, has_veg2: false, leftover_nights: 0 }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct WeekEnv<'a> {
+    pub rules: WeekRules,
+    pub prefs: &'a Preferences,
+    pub history: &'a History,
+    pub house: &'a RuleSet,
+    pub pantry: &'a Pantry,
+    pub budget: &'a Budget,
+    pub season: Season,
+}
+
+impl<'a> WeekEnv<'a> {
+    pub fn new(house: &'a Household, rules: WeekRules) -> Self {
+        WeekEnv {
+            rules,
+            prefs: &house.prefs,
+            history: &house.history,
+            house: &house.rules,
+            pantry: &house.pantry,
+            budget: &house.budget,
+            season: house.season,
+        }
+    }
+
+    fn plate<'b>(&self, cuisine: Cuisine, rules: &'b MealRules) -> GenEnv<'b>
+    where
+        'a: 'b,
+    {
+        GenEnv {
+            cuisine,
+            lock: LockState::default(),
+            has_veg2: self.rules.has_veg2,
+            prefs: self.prefs,
+            history: self.history,
+            rules,
+            pantry: self.pantry,
+            budget: self.budget,
+            season: self.season,
+        }
+    }
+
+    /// House rules for `day`, with weekly caps counted over the rest of
+    /// `week`.
+    fn house_rules(&self, week: &Week, day: usize) -> MealRules {
+        let others: Vec<Vec<&'static Ingredient>> = week
+            .iter()
+            .enumerate()
+            .filter(|(d, _)| *d != day)
+            .map(|(_, p)| plate_items(&p.selection))
+            .filter(|items| !items.is_empty())
+            .collect();
+        self.house.for_meal(day, &others)
+    }
+}
+
+pub fn plate_items(sel: &MealSelection) -> Vec<&'static Ingredient> {
+    [sel.protein, sel.starch, sel.veg1, sel.veg2].into_iter().flatten().collect()
+}
+
+pub fn is_fish(i: &Ingredient) -> bool {
+    catalog().meta(i.id).is_some_and(|m| m.has_tag("protein_seafood"))
+}
+
+fn neighbours(day: usize) -> impl Iterator<Item = usize> {
+    [day.checked_sub(1), Some(day + 1).filter(|&d| d < 7)].into_iter().flatten()
+}
+
+/// What a day has to respect given the rest of the week.
+struct DayRules {
+    /// A batch day wants a protein that makes good leftovers.
+    batch: bool,
+    avoid_proteins: Vec<IngredientId>,
+    want_fish: Option<bool>,
+    avoid_cuisines: Vec<Cuisine>,
+    capped: Vec<Cuisine>,
+}
+
+impl DayRules {
+    fn new(week: &Week, day: usize, options: &[Cuisine], want_fish: Option<bool>, batch: bool) -> Self {
+        // Spread cuisines: none more than its even share of the week
+        let cap = 7usize.div_ceil(options.len().max(1));
+        let others = || week.iter().enumerate().filter(move |(d, _)| *d != day).map(|(_, p)| p);
+        DayRules {
+            batch,
+            avoid_proteins: neighbours(day)
+                .filter_map(|d| week.get(d)?.selection.protein)
+                .map(|p| p.id)
+                .collect(),
+            want_fish,
+            avoid_cuisines: neighbours(day).filter_map(|d| week.get(d)?.cuisine).collect(),
+            capped: options
+                .iter()
+                .copied()
+                .filter(|&c| others().filter(|p| p.cuisine == Some(c)).count() >= cap)
+                .collect(),
+        }
+    }
+
+    fn cuisine_ok(&self, c: Cuisine, level: u8) -> bool {
+        (level >= RELAX_CUISINE_CAP || !self.capped.contains(&c))
+            && (level >= RELAX_CUISINE_REPEATS || !self.avoid_cuisines.contains(&c))
+    }
+
+    fn proteins(&self, c: Cuisine, pool: &[&'static Ingredient], level: u8) -> Vec<&'static Ingredient> {
+        in_cuisine(c, pool)
+            .into_iter()
+            .filter(|p| level >= RELAX_BATCH || !self.batch || transforms_from(p.id).next().is_some())
+            .filter(|p| level >= RELAX_FISH || self.want_fish.is_none_or(|f| is_fish(p) == f))
+            .filter(|p| level >= RELAX_PROTEIN_REPEATS || !self.avoid_proteins.contains(&p.id))
+            .collect()
+    }
+
+    /// The first relaxation level that leaves a protein in `c`, and the
+    /// proteins it leaves.
+    fn first_proteins(&self, c: Cuisine, pool: &[&'static Ingredient]) -> Vec<&'static Ingredient> {
+        (0..RELAX_LEVELS).map(|level| self.proteins(c, pool, level)).find(|p| !p.is_empty()).unwrap_or_default()
+    }
+}
+
+/// Builds one day against the rest of `week`, around whatever `week[day]`
+/// still holds in its locked slots. `want_fish` asks for (or rules out) a
+/// seafood protein; `None` leaves it to chance. A `batch` day first tries
+/// proteins that make good leftovers.
+pub fn plan_day(
+    week: &Week,
+    day: usize,
+    env: WeekEnv,
+    want_fish: Option<bool>,
+    batch: bool,
+    rng: &mut impl Rng,
+) -> DayPlan {
+    let house = env.house_rules(week, day);
+    let mut options = env.prefs.cuisines_with_protein();
+    if options.is_empty() {
+        options = catalog().cuisine_list();
+    }
+    if let Some((c, _)) = house.cuisine.as_ref().filter(|(c, _)| options.contains(c)) {
+        options = vec![*c];
+    }
+    let kept = week.get(day).map(DayPlan::cleared).unwrap_or_default();
+    let (lock, prev) = (kept.locks, kept.selection);
+    // Cuisines that list the locked items, unless none does
+    let listing: Vec<Cuisine> = options.iter().copied().filter(|&c| cuisine_conflicts(&prev, lock, c).is_empty()).collect();
+    if !listing.is_empty() {
+        options = listing;
+    }
+    let plate = |c: Cuisine| GenEnv { lock, ..env.plate(c, &house) };
+    // The protein pool doesn't depend on the plate's cuisine
+    let proteins = match prev.protein {
+        Some(p) if lock.protein => vec![p],
+        _ => options.first().map(|&c| plate(c).pool(SlotKind::Protein)).unwrap_or_default(),
+    };
+    let pool = |c: Cuisine| pairs_with_sides(&prev, c, &slot_candidates(&prev, SlotKind::Protein, c, &proteins, None));
+    let rules = DayRules::new(week, day, &options, want_fish, batch);
+
+    for level in 0..RELAX_LEVELS {
+        let open: Vec<Cuisine> = options
+            .iter()
+            .copied()
+            .filter(|&c| rules.cuisine_ok(c, level) && !rules.proteins(c, &pool(c), level).is_empty())
+            .collect();
+        // Weighted like the single plate, so the week helps trailing cuisines catch up
+        let weights = env.history.cuisine_weights(&open);
+        let Ok(&(cuisine, _)) = weights.choose_weighted(rng, |&(_, w)| w) else { continue };
+        let plate = plate(cuisine);
+        let fit = plate.proteins_within_limits(rules.proteins(cuisine, &pool(cuisine), level), cuisine);
+        let mut sel = prev;
+        sel.protein = choose_weighted(&fit, &sel, plate, rng);
+        let archetype = cascade_from_protein(&mut sel, plate, rng);
+        return DayPlan { cuisine: Some(cuisine), selection: sel, archetype, ..kept };
+    }
+    kept
+}
+
+/// Builds `day` from the previous day's batch, when something on that
+/// plate transforms into an item the day's rules and cuisine allow.
+fn plan_leftover(week: &Week, day: usize, env: WeekEnv, rng: &mut impl Rng) -> Option<DayPlan> {
+    let prev = week.get(day.checked_sub(1)?)?;
+    let house = env.house_rules(week, day);
+    let options: Vec<(&'static Transform, &'static Ingredient)> = [prev.selection.protein, prev.selection.starch]
+        .into_iter()
+        .flatten()
+        .flat_map(|i| transforms_from(i.id))
+        .filter(|t| Some(t.cuisine) != prev.cuisine && house.cuisine.as_ref().is_none_or(|(c, _)| *c == t.cuisine))
+        .filter_map(|t| Some((t, *catalog().ingredients.iter().find(|i| i.id == t.into)?)))
+        // What the batch becomes goes through the same filters as any other pick
+        .filter(|(t, into)| env.plate(t.cuisine, &house).pool(batch_slot(into)).iter().any(|i| i.id == into.id))
+        .collect();
+    let &(t, into) = options.choose(rng)?;
+
+    let plate = env.plate(t.cuisine, &house);
+    let mut sel = MealSelection::default();
+    let mut lock = LockState::default();
+    if batch_slot(into) == SlotKind::Protein {
+        sel.protein = Some(into);
+    } else {
+        // Any protein but last night's that goes with what the batch became
+        let fresh = |p: &Ingredient| prev.selection.protein.is_none_or(|last| last.id != p.id);
+        let proteins: Vec<_> = plate
+            .pool(SlotKind::Protein)
+            .into_iter()
+            .filter(|p| !is_fish(p) && fresh(p) && !pairs_with_protein(Some(p), t.cuisine, &[into], &[]).is_empty())
+            .collect();
+        sel.starch = Some(into);
+        lock.starch = true;
+        let plate = GenEnv { lock, ..plate };
+        let fit = plate.within_limits(plate.candidates(&sel, SlotKind::Protein, &proteins, None), &sel, SlotKind::Protein, None);
+        sel.protein = choose_weighted(&fit, &sel, plate, rng);
+        sel.protein?;
+    }
+    let archetype = cascade_from_protein(&mut sel, GenEnv { lock, ..plate }, rng);
+    Some(DayPlan { cuisine: Some(t.cuisine), selection: sel, archetype, leftover: Some(t), ..DayPlan::default() })
+}
+
+/// The slot a batch's leftover fills on the next day's plate.
+fn batch_slot(into: &Ingredient) -> SlotKind {
+    if into.category == Category::Protein { SlotKind::Protein } else { SlotKind::Starch }
+}
+
+/// Days that get the week's fish nights: locked fish days count first, the
+/// rest are drawn from the unlocked days.
+fn fish_days(week: &Week, wanted: usize, rng: &mut impl Rng) -> Vec<usize> {
+    let locked_fish = week.iter().filter(|p| p.locked && p.selection.protein.is_some_and(is_fish)).count();
+    let mut open: Vec<usize> = week.iter().enumerate().filter(|(_, p)| !p.locked).map(|(d, _)| d).collect();
+    open.shuffle(rng);
+    open.truncate(wanted.saturating_sub(locked_fish));
+    open
+}
+
+/// Batch days for the week's leftover nights: days with nothing locked whose
+/// next day has nothing locked either, with no fish night in either and no
+/// two pairs touching.
+fn batch_days(week: &Week, wanted: usize, fish: &[usize], rng: &mut impl Rng) -> Vec<usize> {
+    let locked = week.iter().filter(|p| p.locked && p.leftover.is_some()).count();
+    let free = |d: usize| week.get(d).is_some_and(|p| !p.locked && p.locks == LockState::default()) && !fish.contains(&d);
+    let mut open: Vec<usize> = (0..week.len().saturating_sub(1)).filter(|&d| free(d) && free(d + 1)).collect();
+    open.shuffle(rng);
+    let mut out: Vec<usize> = Vec::new();
+    for d in open {
+        if out.len() + locked >= wanted {
+            break;
+        }
+        if out.iter().all(|&b| b.abs_diff(d) > 1) {
+            out.push(d);
+        }
+    }
+    out
+}
+
+/// Regenerates every unlocked day from `seed`, Monday first. A batch day's
+/// next day is built from its leftovers when the batch allows it.
+pub fn plan_week(seed: u64, prev: &Week, env: WeekEnv) -> Week {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut week = *prev;
+    for p in week.iter_mut().filter(|p| !p.locked) {
+        *p = p.cleared();
+    }
+    let fish = fish_days(&week, env.rules.fish_nights, &mut rng);
+    let batches = batch_days(&week, env.rules.leftover_nights, &fish, &mut rng);
+    for day in 0..week.len() {
+        if week.get(day).is_some_and(|p| !p.locked && p.cuisine.is_none()) {
+            let batch = batches.contains(&day);
+            let plan = plan_day(&week, day, env, Some(fish.contains(&day)), batch, &mut rng);
+            if let Some(p) = week.get_mut(day) {
+                *p = plan;
+            }
+            if let Some(next) = batch.then(|| plan_leftover(&week, day + 1, env, &mut rng)).flatten() {
+                if let Some(p) = week.get_mut(day + 1) {
+                    *p = next;
+                }
+            }
+        }
+    }
+    week
+}
+
+/// A new plate for one day that still fits the rest of the week: its
+/// neighbours' proteins and cuisines, and the fish-night count.
+pub fn reroll_day(seed: u64, week: &Week, day: usize, env: WeekEnv) -> DayPlan {
+    let n = week.get(day).map_or(0, |p| p.rerolls) + 1;
+    let mut rng = derived_rng(seed, &format!("day-{day}-reroll-{n}"));
+    let mut others = *week;
+    if let Some(p) = others.get_mut(day) {
+        *p = p.cleared();
+    }
+    let fish_elsewhere =
+        others.iter().enumerate().filter(|&(d, p)| d != day && p.selection.protein.is_some_and(is_fish)).count();
+    let want_fish = Some(fish_elsewhere < env.rules.fish_nights);
+    DayPlan { rerolls: n, ..plan_day(&others, day, env, want_fish, false, &mut rng) }
+}
+
+/// A new item for one slot of `day`, keeping the rest of the plate. A new
+/// protein stays in the day's cuisine, still has to fit the week (its
+/// neighbours' proteins, the fish nights) and pair with the locked sides,
+/// and the unlocked sides are rebuilt around it.
+pub fn reroll_slot(seed: u64, week: &Week, day: usize, slot: SlotKind, env: WeekEnv) -> DayPlan {
+    let Some(&plan) = week.get(day) else { return DayPlan::default() };
+    let Some(cuisine) = plan.cuisine else { return plan };
+    let n = plan.rerolls + 1;
+    let mut rng = derived_rng(seed, &format!("day-{day}-{slot:?}-reroll-{n}"));
+    let house = env.house_rules(week, day);
+    let plate = GenEnv { lock: plan.locks, ..env.plate(cuisine, &house) };
+    let mut sel = plan.selection;
+    let archetype = if slot == SlotKind::Protein {
+        clear_unlocked_sides(&mut sel, plan.locks);
+        let mut others = *week;
+        if let Some(p) = others.get_mut(day) {
+            *p = DayPlan::default();
+        }
+        let fish_elsewhere = others.iter().filter(|p| p.selection.protein.is_some_and(is_fish)).count();
+        let rules = DayRules::new(&others, day, &[cuisine], Some(fish_elsewhere < env.rules.fish_nights), false);
+        let fit = slot_candidates(&sel, SlotKind::Protein, cuisine, &plate.pool(SlotKind::Protein), None);
+        let proteins = rules.first_proteins(cuisine, &pairs_with_sides(&sel, cuisine, &fit));
+        sel.protein = choose_weighted(&plate.proteins_within_limits(proteins, cuisine), &sel, plate, &mut rng);
+        cascade_from_protein(&mut sel, plate, &mut rng)
+    } else {
+        let pick = reroll_side(&sel, slot, plate, plan.archetype, &mut rng);
+        set_item(&mut sel, slot, pick);
+        plan.archetype
+    };
+    DayPlan { selection: sel, archetype, rerolls: n, leftover: plan.leftover_on(&sel), ..plan }
+}
+
+/// A manual pick for one slot, as on the single-meal page: an item from
+/// another cuisine moves the day there (dropping a protein that doesn't
+/// pair with it anywhere), and a new protein rebuilds the unlocked sides.
+/// Week rules are reported by `week_issues`, not enforced.
+pub fn pick_item(seed: u64, week: &Week, day: usize, slot: SlotKind, opt: SlotOption, env: WeekEnv) -> DayPlan {
+    let Some(&plan) = week.get(day) else { return DayPlan::default() };
+    let item = opt.ingredient;
+    let Some(cuisine) = opt.target_cuisine.or(plan.cuisine).or_else(|| item.cuisines.first().map(|(c, _)| *c)) else {
+        return plan;
+    };
+    let mut rng = derived_rng(seed, &format!("day-{day}-pick-{slot:?}-{}", item.id.as_str()));
+    let mut sel = plan.selection;
+    if opt.target_cuisine.is_some() && opt.clear_protein {
+        sel.protein = None;
+    }
+    set_item(&mut sel, slot, Some(item));
+    let archetype = if slot == SlotKind::Protein {
+        clear_unlocked_sides(&mut sel, plan.locks);
+        let house = env.house_rules(week, day);
+        cascade_from_protein(&mut sel, GenEnv { lock: plan.locks, ..env.plate(cuisine, &house) }, &mut rng)
+    } else {
+        refit_archetype(plan.archetype, &sel, cuisine, &mut rng)
+    };
+    DayPlan { cuisine: Some(cuisine), selection: sel, archetype, leftover: plan.leftover_on(&sel), ..plan }
+}
+
+/// One line of the week's shopping list.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShoppingLine {
+    pub item: &'static Ingredient,
+    pub amount: Amount,
+    /// Part of it is a double batch whose leftovers feed the next day.
+    pub batch: bool,
+}
+
+/// What the planned week needs beyond `pantry`'s stock. A leftover day's
+/// transformed item isn't bought; the batch the day before is doubled.
+pub fn week_shopping(week: &Week, pantry: &Pantry) -> Vec<ShoppingLine> {
+    let mut dinners: Vec<(&'static Ingredient, u32, bool)> = Vec::new();
+    let mut add = |i: &'static Ingredient, batch: bool| match dinners.iter_mut().find(|(x, _, _)| x.id == i.id) {
+        Some(entry) => {
+            entry.1 += 1;
+            entry.2 |= batch;
+        }
+        None => dinners.push((i, 1, batch)),
+    };
+    for (d, plan) in week.iter().enumerate() {
+        let prev = d.checked_sub(1).and_then(|p| week.get(p));
+        let batch = plan.leftover.and_then(|t| {
+            Some((t, plate_items(&prev?.selection).into_iter().find(|i| i.id == t.from)?))
+        });
+        for i in plate_items(&plan.selection) {
+            match batch.filter(|(t, _)| t.into == i.id) {
+                Some((_, from)) => add(from, true),
+                None => add(i, false),
+            }
+        }
+    }
+    let order = |c: Category| [Category::Protein, Category::Starch, Category::Veg].iter().position(|x| *x == c);
+    dinners.sort_by_key(|(i, _, _)| order(i.category));
+    dinners
+        .into_iter()
+        .filter_map(|(i, n, batch)| {
+            let one = meal_amount(i);
+            let need = Amount { qty: one.qty * f64::from(n), ..one };
+            pantry.shortfall_of(i, need).map(|amount| ShoppingLine { item: i, amount, batch })
+        })
+        .collect()
+}
+
+/// Week-level and house rules the current plan breaks, in words.
+pub fn week_issues(week: &Week, env: WeekEnv) -> Vec<String> {
+    let rules = env.rules;
+    let mut out = Vec::new();
+    for ((d, name), plan) in DAY_NAMES.iter().enumerate().zip(week) {
+        if let Some(c) = plan.cuisine.filter(|_| plan.selection.protein.is_some()) {
+            let broken = env.house_rules(week, d).violations(c, &plan.selection, plan.archetype);
+            out.extend(broken.into_iter().map(|b| format!("{name}: {b}")));
+        }
+    }
+    let named: Vec<(&str, &DayPlan)> = DAY_NAMES.iter().copied().zip(week).collect();
+    for pair in named.windows(2) {
+        let [(day_a, a), (day_b, b)] = pair else { continue };
+        if let Some(t) = b.leftover.filter(|t| !plate_items(&a.selection).iter().any(|i| i.id == t.from)) {
+            let from = catalog().ingredients.iter().find(|i| i.id == t.from).map_or("", |i| i.name);
+            out.push(format!("{day_b}'s leftovers need {from} on {day_a}"));
+        }
+        if let (Some(pa), Some(pb)) = (a.selection.protein, b.selection.protein) {
+            if pa.id == pb.id && b.leftover.is_none_or(|t| t.from != pa.id) {
+                out.push(format!("{} on {day_a} and {day_b}", pa.name));
+            }
+        }
+        if let (Some(ca), Some(cb)) = (a.cuisine, b.cuisine) {
+            if ca == cb {
+                out.push(format!("{} two nights running ({day_a}, {day_b})", catalog().label(ca)));
+            }
+        }
+    }
+    let fish = week.iter().filter(|p| p.selection.protein.is_some_and(is_fish)).count();
+    if week.iter().any(|p| p.selection.protein.is_some()) && fish != rules.fish_nights {
+        out.push(format!("{fish} fish nights planned; the week asks for {}", rules.fish_nights));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::pages::history::HistoryEntry;
+    use crate::components::pages::pantry::PantryMode;
+    use crate::components::pages::rules::parse_rules;
+
+    fn item(id: &str) -> &'static Ingredient {
+        catalog().ingredients.iter().copied().find(|i| i.id.as_str() == id).unwrap_or_else(|| panic!("no {id}"))
+    }
+
+    /// Runs `f` against the built-in catalog, default household settings
+    /// and the house rules in `house`.
+    fn with_env<T>(rules: WeekRules, house: &str, f: impl FnOnce(WeekEnv) -> T) -> T {
+        with_stores(rules, house, &History::default(), &Pantry::default(), f)
+    }
+
+    /// `with_env` over a given history and pantry.
+    fn with_stores<T>(rules: WeekRules, house: &str, history: &History, pantry: &Pantry, f: impl FnOnce(WeekEnv) -> T) -> T {
+        let (prefs, budget) = (Preferences::default(), Budget::default());
+        let house = parse_rules(house);
+        f(WeekEnv { rules, prefs: &prefs, history, house: &house, pantry, budget: &budget, season: Season::default() })
+    }
+
+    #[test]
+    fn a_cuisine_on_cooldown_sits_the_week_out() {
+        let history = History {
+            entries: vec![HistoryEntry { day: 20_000, cuisine: Cuisine::Latin.id().to_string(), items: Vec::new() }],
+            today: 20_000,
+            ..History::default()
+        };
+        with_stores(WeekRules::default(), "", &history, &Pantry::default(), |env| {
+            for seed in 0..20 {
+                let week = plan_week(seed, &Week::default(), env);
+                let cuisines: Vec<_> = week.iter().map(|p| p.cuisine).collect();
+                assert!(!cuisines.contains(&Some(Cuisine::Latin)), "seed {seed}: {cuisines:?}");
+            }
+        });
+    }
+
+    fn proteins(week: &Week) -> Vec<Option<&'static str>> {
+        week.iter().map(|p| p.selection.protein.map(|i| i.id.as_str())).collect()
+    }
+
+    #[test]
+    fn no_protein_two_nights_running() {
+        with_env(WeekRules::default(), "", |env| {
+            for seed in 0..20 {
+                let week = plan_week(seed, &Week::default(), env);
+                let p = proteins(&week);
+                assert!(p.iter().all(Option::is_some), "seed {seed}: {p:?}");
+                assert!(p.windows(2).all(|w| w.first() != w.get(1)), "seed {seed}: {p:?}");
+            }
+        });
+    }
+
+    #[test]
+    fn the_week_has_its_fish_nights() {
+        for fish_nights in 0..=3 {
+            with_env(WeekRules { fish_nights, ..WeekRules::default() }, "", |env| {
+                for seed in 0..10 {
+                    let week = plan_week(seed, &Week::default(), env);
+                    let fish = week.iter().filter(|p| p.selection.protein.is_some_and(is_fish)).count();
+                    assert_eq!(fish, fish_nights, "seed {seed}");
+                }
+            });
+        }
+    }
+
+    #[test]
+    fn cuisines_spread_over_the_week() {
+        with_env(WeekRules::default(), "", |env| {
+            let cap = 7usize.div_ceil(env.prefs.cuisines_with_protein().len());
+            for seed in 0..20 {
+                let week = plan_week(seed, &Week::default(), env);
+                let cuisines: Vec<Cuisine> = week.iter().filter_map(|p| p.cuisine).collect();
+                assert_eq!(cuisines.len(), 7, "seed {seed}");
+                assert!(cuisines.windows(2).all(|w| w.first() != w.get(1)), "seed {seed}: {cuisines:?}");
+                for c in &cuisines {
+                    assert!(cuisines.iter().filter(|x| *x == c).count() <= cap, "seed {seed}: {cuisines:?}");
+                }
+                assert!(week_issues(&week, env).is_empty(), "seed {seed}: {:?}", week_issues(&week, env));
+            }
+        });
+    }
+
+    /// Each level relaxes one more rule, and the protein repeat goes last.
+    #[test]
+    fn rules_relax_one_level_at_a_time() {
+        let steak = DayPlan {
+            cuisine: Some(Cuisine::Latin),
+            selection: MealSelection { protein: Some(item("steak")), ..MealSelection::default() },
+            ..DayPlan::default()
+        };
+        let week: Week = [steak, DayPlan::default(), steak, DayPlan::default(), DayPlan::default(), DayPlan::default(), DayPlan::default()];
+        let pool = [item("steak"), item("shrimp"), item("chicken_thighs"), item("ground_turkey")];
+
+        let rules = DayRules::new(&week, 1, &[Cuisine::Latin], Some(false), true);
+        assert!(!rules.cuisine_ok(Cuisine::Latin, 0));
+        assert!(rules.cuisine_ok(Cuisine::Latin, RELAX_CUISINE_REPEATS));
+        let ids = |level| rules.proteins(Cuisine::Latin, &pool, level).iter().map(|p| p.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(ids(0), vec!["chicken_thighs"]);
+        assert_eq!(ids(RELAX_BATCH), vec!["chicken_thighs", "ground_turkey"]);
+        assert_eq!(ids(RELAX_FISH), vec!["shrimp", "chicken_thighs", "ground_turkey"]);
+        assert_eq!(ids(RELAX_PROTEIN_REPEATS), vec!["steak", "shrimp", "chicken_thighs", "ground_turkey"]);
+        assert_eq!(rules.first_proteins(Cuisine::Latin, &[item("steak")]), vec![item("steak")]);
+    }
+
+    /// A cuisine rule for every day leaves the week nothing to spread, so
+    /// the repeats are relaxed rather than leaving days empty.
+    #[test]
+    fn a_week_that_cannot_spread_still_fills() {
+        with_env(WeekRules::default(), "every day: latin", |env| {
+            let week = plan_week(3, &Week::default(), env);
+            assert!(week.iter().all(|p| p.cuisine == Some(Cuisine::Latin) && p.selection.protein.is_some()));
+            assert!(week_issues(&week, env).iter().any(|i| i.contains("two nights running")));
+        });
+    }
+
+    #[test]
+    fn locked_days_and_slots_survive_a_reroll() {
+        with_env(WeekRules::default(), "", |env| {
+            let mut week = plan_week(5, &Week::default(), env);
+            let wed = week[2];
+            week[2].locked = true;
+            week[4].locks.starch = true;
+            let fri_starch = week[4].selection.starch;
+
+            let next = plan_week(6, &week, env);
+            assert_eq!(next[2].selection, wed.selection);
+            assert_eq!(next[2].cuisine, wed.cuisine);
+            assert_eq!(next[4].selection.starch, fri_starch);
+
+            let thu = reroll_day(6, &next, 3, env);
+            assert_eq!(thu.rerolls, 1);
+            assert_ne!(thu.selection.protein, next[2].selection.protein);
+            let fri = reroll_slot(6, &next, 4, SlotKind::Protein, env);
+            assert_eq!(fri.selection.starch, fri_starch);
+        });
+    }
+
+    /// Monday's steak cooked double and Tuesday's tacos made from it.
+    fn steak_pair() -> Week {
+        let t = transforms_from(item("steak").id).next().expect("steak has a transform");
+        let mut week = Week::default();
+        week[0] = DayPlan {
+            cuisine: Some(Cuisine::American),
+            selection: MealSelection { protein: Some(item("steak")), starch: Some(item("baked_potato")), ..MealSelection::default() },
+            ..DayPlan::default()
+        };
+        week[1] = DayPlan {
+            cuisine: Some(t.cuisine),
+            selection: MealSelection { protein: Some(item("steak")), starch: Some(item("tortillas")), ..MealSelection::default() },
+            leftover: Some(t),
+            ..DayPlan::default()
+        };
+        week
+    }
+
+    #[test]
+    fn leftover_nights_are_built_from_the_batch() {
+        let mut pairs = 0;
+        with_env(WeekRules { leftover_nights: 2, ..WeekRules::default() }, "", |env| {
+            for seed in 0..10 {
+                let week = plan_week(seed, &Week::default(), env);
+                for (d, plan) in week.iter().enumerate().skip(1) {
+                    let Some(t) = plan.leftover else { continue };
+                    let batch = plate_items(&week[d - 1].selection);
+                    assert!(batch.iter().any(|i| i.id == t.from), "seed {seed}, day {d}");
+                    assert_eq!(plan.cuisine, Some(t.cuisine), "seed {seed}, day {d}");
+                    assert!(plate_items(&plan.selection).iter().any(|i| i.id == t.into), "seed {seed}, day {d}");
+                    if item(t.from.as_str()).category == Category::Protein {
+                        assert_eq!(plan.selection.protein, week[d - 1].selection.protein, "seed {seed}, day {d}");
+                    }
+                    pairs += 1;
+                }
+                assert!(week_issues(&week, env).is_empty(), "seed {seed}: {:?}", week_issues(&week, env));
+            }
+        });
+        assert!(pairs > 0, "no seed planned a leftover night");
+    }
+
+    #[test]
+    fn a_leftover_night_keeps_to_the_pantry() {
+        let mut pantry = Pantry::default();
+        pantry.mode = PantryMode::Require;
+        for &i in catalog().ingredients.iter().filter(|i| i.id.as_str() != "fried_rice") {
+            pantry.add(i, 10.0);
+        }
+        let mut week = Week::default();
+        week[0] = DayPlan {
+            cuisine: Some(Cuisine::American),
+            selection: MealSelection { protein: Some(item("chicken_breast")), starch: Some(item("jasmine_rice")), ..MealSelection::default() },
+            ..DayPlan::default()
+        };
+        let mut planned = 0;
+        with_stores(WeekRules::default(), "", &History::default(), &pantry, |env| {
+            for seed in 0..20 {
+                let Some(plan) = plan_leftover(&week, 1, env, &mut SmallRng::seed_from_u64(seed)) else { continue };
+                assert_eq!(plan.leftover.map(|t| t.into.as_str()), Some("chicken_breast"), "seed {seed}");
+                assert!(plate_items(&plan.selection).iter().all(|i| pantry.has(i)), "seed {seed}: {:?}", plan.selection);
+                planned += 1;
+            }
+        });
+        assert!(planned > 0, "no seed planned a leftover night");
+    }
+
+    #[test]
+    fn batch_days_pair_free_days_apart() {
+        let mut week = Week::default();
+        week[3].locked = true;
+        let mut rng = SmallRng::seed_from_u64(1);
+        for _ in 0..20 {
+            let days = batch_days(&week, 3, &[0], &mut rng);
+            assert!(days.iter().all(|&d| d != 0 && d != 2 && d != 3 && d < 6), "{days:?}");
+            assert!(days.iter().all(|&a| days.iter().all(|&b| a == b || a.abs_diff(b) > 1)), "{days:?}");
+        }
+    }
+
+    #[test]
+    fn the_batch_is_bought_double() {
+        let list = week_shopping(&steak_pair(), &Pantry::default());
+        let steak = list.iter().find(|l| l.item.id.as_str() == "steak").expect("steak on the list");
+        assert!(steak.batch);
+        assert!((steak.amount.qty - 2.0 * meal_amount(item("steak")).qty).abs() < 1e-9);
+        let potato = list.iter().find(|l| l.item.id.as_str() == "baked_potato").expect("potato on the list");
+        assert!(!potato.batch);
+    }
+
+    #[test]
+    fn rerolling_the_batch_day_flags_the_leftovers() {
+        with_env(WeekRules { fish_nights: 0, ..WeekRules::default() }, "", |env| {
+            let mut week = steak_pair();
+            assert!(week_issues(&week, env).is_empty(), "{:?}", week_issues(&week, env));
+            week[0] = reroll_day(1, &week, 0, env);
+            let issues = week_issues(&week, env);
+            assert!(issues.contains(&"Tuesday's leftovers need Steak on Monday".to_string()), "{issues:?}");
+        });
+    }
+}
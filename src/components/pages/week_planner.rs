@@ -0,0 +1,317 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+
+use crate::content::{catalog, get_proteins, get_starches, get_vegs, IngredientId};
+
+use super::content_packs::CATALOG_REV;
+use super::generator_logic::generate_slot_options;
+use super::meal_slot::option_look;
+use super::meal_types::{fusion_cuisines, get_item, is_locked, toggle_lock, Household, Season, SlotKind, SlotOption};
+use super::icons::{ICON_LOCK, ICON_REROLL, ICON_UNLOCK};
+use super::pantry::PANTRY;
+use super::preferences::PREFS;
+use super::timing::meal_time;
+use super::week_plan::{
+    is_fish, pick_item, plan_week, reroll_day, reroll_slot, week_issues, week_shopping, DayPlan, Week, WeekEnv, WeekRules,
+    DAY_NAMES,
+};
+
+#[derive(Clone, Copy)]
+struct WeekCtx {
+    week: Signal<Week>,
+    rules: Signal<WeekRules>,
+    seed: Signal<u64>,
+    /// The day and slot whose picker is open.
+    picking: Signal<Option<(usize, SlotKind)>>,
+}
+
+impl WeekCtx {
+    /// Runs `f` with the current household settings as a `WeekEnv`.
+    fn with_env<T>(&self, f: impl FnOnce(WeekEnv) -> T) -> T {
+        let house = Household::now();
+        f(WeekEnv::new(&house, *self.rules.read()))
+    }
+}
+
+#[component]
+pub fn WeekPlanner() -> Element {
+    let week = use_signal(|| [DayPlan::default(); 7]);
+    let mut rules = use_signal(WeekRules::default);
+    let seed = use_signal(|| 0u64);
+    let picking = use_signal::<Option<(usize, SlotKind)>>(|| None);
+    let mut ctx = WeekCtx { week, rules, seed, picking };
+    // Re-render against the merged catalog whenever a content pack loads
+    let _catalog_rev = CATALOG_REV();
+
+    let generate = move |_| {
+        let s = rand::random::<u64>();
+        let next = ctx.with_env(|env| plan_week(s, &ctx.week.read(), env));
+        ctx.seed.set(s);
+        ctx.picking.set(None);
+        ctx.week.set(next);
+    };
+
+    let r = *rules.read();
+    let planned = week.read().iter().any(|d| d.selection.protein.is_some());
+    let issues = ctx.with_env(|env| week_issues(&week.read(), env));
+    let shopping = week_shopping(&week.read(), &PANTRY.read());
+    let days: Vec<(usize, DayPlan)> = week.read().iter().copied().enumerate().collect();
+
+    rsx! {
+        div { class: "section generator-page",
+            div { class: "generator-header", style: "animation:fadeSlideUp 0.5s var(--ease-out) both;",
+                h1 { class: "generator-title", "The Week Ahead" }
+                p { class: "generator-subtitle", "Seven dinners, no repeats back-to-back" }
+                a { class: "page-link", href: "/", "\u{2190} One meal" }
+            }
+
+            div { class: "household week-rules",
+                div { class: "household__row",
+                    span { class: "household__label", "Fish nights" }
+                    button {
+                        class: "cuisine-pill",
+                        onclick: move |_| { let f = &mut rules.write().fish_nights; *f = f.saturating_sub(1); },
+                        "\u{2212}"
+                    }
+                    span { class: "history__days", "{r.fish_nights}" }
+                    button {
+                        class: "cuisine-pill",
+                        onclick: move |_| { let f = &mut rules.write().fish_nights; *f = (*f + 1).min(7); },
+                        "+"
+                    }
+                }
+                div { class: "household__row",
+                    span { class: "household__label", "Leftover nights" }
+                    button {
+                        class: "cuisine-pill",
+                        onclick: move |_| { let n = &mut rules.write().leftover_nights; *n = n.saturating_sub(1); },
+                        "\u{2212}"
+                    }
+                    span { class: "history__days", "{r.leftover_nights}" }
+                    button {
+                        class: "cuisine-pill",
+                        title: "Cook double one night and turn the rest into the next night's dinner",
+                        onclick: move |_| { let n = &mut rules.write().leftover_nights; *n = (*n + 1).min(3); },
+                        "+"
+                    }
+                }
+                div { class: "household__row",
+                    span { class: "household__label", "Sides" }
+                    button {
+                        class: if r.has_veg2 { "cuisine-pill" } else { "cuisine-pill cuisine-pill--active" },
+                        onclick: move |_| rules.write().has_veg2 = false,
+                        "One veg"
+                    }
+                    button {
+                        class: if r.has_veg2 { "cuisine-pill cuisine-pill--active" } else { "cuisine-pill" },
+                        onclick: move |_| rules.write().has_veg2 = true,
+                        "Two veg"
+                    }
+                }
+            }
+
+            button { onclick: generate, class: "btn btn--primary generate-btn",
+                if planned { "Plan a New Week" } else { "Plan the Week" }
+            }
+
+            if planned {
+                div { class: "week",
+                    for (day, plan) in days {
+                        {render_day(day, plan, ctx)}
+                    }
+                }
+                if !issues.is_empty() {
+                    ul { class: "week__issues",
+                        for issue in issues {
+                            li { "{issue}" }
+                        }
+                    }
+                }
+                if !shopping.is_empty() {
+                    div { class: "household week-shopping",
+                        span { class: "household__title", "Shopping list" }
+                        ul { class: "week-shopping__list",
+                            for line in shopping {
+                                li { class: "week-shopping__item",
+                                    span { class: "pantry__name", "{line.item.name}" }
+                                    span { class: "pantry__unit", "{line.amount}" }
+                                    if line.batch {
+                                        span { class: "week-shopping__batch", "includes a double batch" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_day(day: usize, plan: DayPlan, mut ctx: WeekCtx) -> Element {
+    let name = DAY_NAMES.get(day).copied().unwrap_or_default();
+    let cuisine = plan.cuisine.map(|c| catalog().label(c)).unwrap_or_default();
+    let sel = plan.selection;
+    let fish = sel.protein.is_some_and(is_fish);
+    let fused = plan.cuisine.is_some_and(|c| !fusion_cuisines(&sel, c).is_empty());
+    let minutes = meal_time(&sel, plan.archetype).minutes.total;
+    let batch = ctx.week.read().get(day + 1).and_then(|next| next.leftover).is_some_and(|t| {
+        [sel.protein, sel.starch].into_iter().flatten().any(|i| i.id == t.from)
+    });
+    let leftover = plan.leftover.map(|t| {
+        let prev = day.checked_sub(1).and_then(|d| DAY_NAMES.get(d)).copied().unwrap_or_default();
+        let from = catalog().ingredients.iter().find(|i| i.id == t.from).map_or("", |i| i.name);
+        format!("Leftovers: {prev}'s {}, {}", from.to_lowercase(), t.note)
+    });
+    let card_class = if plan.locked { "week-day week-day--locked" } else { "week-day" };
+    let keep_class = if plan.locked { "slot-btn slot-btn--lock slot-btn--locked" } else { "slot-btn slot-btn--lock" };
+    let has_veg2 = ctx.rules.read().has_veg2;
+    let slots = SlotKind::ALL.into_iter().filter(move |&s| s != SlotKind::Veg2 || has_veg2 || sel.veg2.is_some());
+    let picking = ctx.picking.read().filter(|(d, _)| *d == day).map(|(_, s)| s);
+
+    rsx! {
+        div { class: "{card_class}",
+            div { class: "week-day__header",
+                span { class: "week-day__name", "{name}" }
+                span { class: "week-day__cuisine", "{cuisine}" }
+                if let Some(a) = plan.archetype {
+                    span { class: "archetype-badge", "{a.label}" }
+                }
+                if fused {
+                    span { class: "archetype-badge archetype-badge--fusion", "Fusion" }
+                }
+                if fish {
+                    span { class: "meal-slot__badge week-day__fish", "Fish night" }
+                }
+                if batch {
+                    span { class: "meal-slot__badge week-day__batch", "Cook double" }
+                }
+                div { class: "meal-slot__actions",
+                    button {
+                        class: "{keep_class}",
+                        title: if plan.locked { "Unlock" } else { "Keep this day" },
+                        onclick: move |_| {
+                            let mut w = ctx.week.write();
+                            if let Some(p) = w.get_mut(day) {
+                                p.locked = !p.locked;
+                            }
+                        },
+                        span { dangerous_inner_html: if plan.locked { ICON_LOCK } else { ICON_UNLOCK } }
+                    }
+                    button {
+                        class: "slot-btn",
+                        title: "Re-roll this day",
+                        disabled: plan.locked,
+                        onclick: move |_| {
+                            let next = ctx.with_env(|env| reroll_day(*ctx.seed.read(), &ctx.week.read(), day, env));
+                            if let Some(p) = ctx.week.write().get_mut(day) {
+                                *p = next;
+                            }
+                        },
+                        span { dangerous_inner_html: ICON_REROLL }
+                    }
+                }
+            }
+            div { class: "week-day__body",
+                div { class: "week-day__slots",
+                    for slot in slots {
+                        {render_day_slot(day, slot, plan, ctx)}
+                    }
+                }
+                if minutes > 0 {
+                    span { class: "week-day__time", "~{minutes} min" }
+                }
+            }
+            if let Some(note) = leftover {
+                p { class: "week-day__leftover", "{note}" }
+            }
+            if let Some(slot) = picking {
+                div { class: "meal-slot__picker-grid week-day__picker",
+                    for opt in day_options(slot, &plan) {
+                        {render_pick(day, slot, opt, plan, ctx)}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One slot of a day: its item (click to pick another), a lock that keeps
+/// it through Plan and day re-rolls, and a re-roll of its own.
+fn render_day_slot(day: usize, slot: SlotKind, plan: DayPlan, mut ctx: WeekCtx) -> Element {
+    let item = get_item(&plan.selection, slot);
+    let locked = is_locked(plan.locks, slot);
+    let open = *ctx.picking.read() == Some((day, slot));
+    let keep_class = if locked { "slot-btn slot-btn--lock slot-btn--locked" } else { "slot-btn slot-btn--lock" };
+    let name_class = if slot == SlotKind::Protein { "week-slot__name week-slot__name--protein" } else { "week-slot__name" };
+
+    rsx! {
+        div { class: "week-slot",
+            span { class: "week-slot__label", title: "{slot.label()}", "{slot.short_label()}" }
+            button {
+                class: "{name_class}",
+                title: "Pick the {slot.label().to_lowercase()}",
+                onclick: move |_| ctx.picking.set(if open { None } else { Some((day, slot)) }),
+                {item.map_or("\u{2014}", |i| i.name)}
+            }
+            button {
+                class: "{keep_class}",
+                title: if locked { "Unlock" } else { "Keep this" },
+                disabled: plan.locked,
+                onclick: move |_| {
+                    if let Some(p) = ctx.week.write().get_mut(day) {
+                        toggle_lock(&mut p.locks, slot);
+                    }
+                },
+                span { dangerous_inner_html: if locked { ICON_LOCK } else { ICON_UNLOCK } }
+            }
+            button {
+                class: "slot-btn",
+                title: "Re-roll",
+                disabled: plan.locked || locked || plan.cuisine.is_none(),
+                onclick: move |_| {
+                    let next = ctx.with_env(|env| reroll_slot(*ctx.seed.read(), &ctx.week.read(), day, slot, env));
+                    if let Some(p) = ctx.week.write().get_mut(day) {
+                        *p = next;
+                    }
+                },
+                span { dangerous_inner_html: ICON_REROLL }
+            }
+        }
+    }
+}
+
+/// The picker for `slot`, marked up against the day's cuisine and protein
+/// as on the single-meal page.
+fn day_options(slot: SlotKind, plan: &DayPlan) -> Vec<SlotOption> {
+    let Some(cuisine) = plan.cuisine.or_else(|| catalog().cuisine_list().first().copied()) else { return Vec::new() };
+    let sel = plan.selection;
+    let (items, protein, other_veg) = match slot {
+        SlotKind::Protein => (get_proteins(), None, None),
+        SlotKind::Starch => (get_starches(), sel.protein, None),
+        SlotKind::Veg1 => (get_vegs(), sel.protein, sel.veg2),
+        SlotKind::Veg2 => (get_vegs(), sel.protein, sel.veg1),
+    };
+    let exclude: Vec<IngredientId> = other_veg.map(|v| v.id).into_iter().collect();
+    generate_slot_options(&items, protein, cuisine, &exclude, &PREFS.read(), Season::now())
+}
+
+fn render_pick(day: usize, slot: SlotKind, opt: SlotOption, plan: DayPlan, mut ctx: WeekCtx) -> Element {
+    let (cls, title) = option_look(&opt, get_item(&plan.selection, slot));
+
+    rsx! {
+        button {
+            class: "{cls}",
+            title: "{title}",
+            onclick: move |_| {
+                let next = ctx.with_env(|env| pick_item(*ctx.seed.read(), &ctx.week.read(), day, slot, opt, env));
+                if let Some(d) = ctx.week.write().get_mut(day) {
+                    *d = next;
+                }
+                ctx.picking.set(None);
+            },
+            "{opt.ingredient.name}"
+        }
+    }
+}
@@ -0,0 +1,198 @@
+// Allergens — per-ingredient allergen data, parallel to INGREDIENTS
+//
+// Kept apart from diet.rs: a diet is a preference the cook can bend, an
+// allergy is a hard exclusion. Entries follow the same "as usually cooked"
+// reading as the diet table.
+
+#[allow(clippy::wildcard_imports)]
+use super::meal_data::ids::*;
+use serde::{Deserialize, Serialize};
+
+use super::meal_data::IngredientId;
+use Allergen::{Dairy, Egg, Fish, Gluten, Sesame, Shellfish, Soy};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Allergen {
+    Dairy,
+    Egg,
+    Gluten,
+    Shellfish,
+    Fish,
+    Soy,
+    TreeNut,
+    Peanut,
+    Sesame,
+}
+
+impl Allergen {
+    pub const ALL: [Allergen; 9] = [
+        Allergen::Dairy,
+        Allergen::Egg,
+        Allergen::Gluten,
+        Allergen::Shellfish,
+        Allergen::Fish,
+        Allergen::Soy,
+        Allergen::TreeNut,
+        Allergen::Peanut,
+        Allergen::Sesame,
+    ];
+
+    pub const fn id(self) -> &'static str {
+        match self {
+            Allergen::Dairy => "dairy",
+            Allergen::Egg => "egg",
+            Allergen::Gluten => "gluten",
+            Allergen::Shellfish => "shellfish",
+            Allergen::Fish => "fish",
+            Allergen::Soy => "soy",
+            Allergen::TreeNut => "tree_nut",
+            Allergen::Peanut => "peanut",
+            Allergen::Sesame => "sesame",
+        }
+    }
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Allergen::Dairy => "Dairy",
+            Allergen::Egg => "Egg",
+            Allergen::Gluten => "Gluten",
+            Allergen::Shellfish => "Shellfish",
+            Allergen::Fish => "Fish",
+            Allergen::Soy => "Soy",
+            Allergen::TreeNut => "Tree nut",
+            Allergen::Peanut => "Peanut",
+            Allergen::Sesame => "Sesame",
+        }
+    }
+
+    /// Picker tooltip for an item the household is allergic to.
+    pub const fn warning(self) -> &'static str {
+        match self {
+            Allergen::Dairy => "Allergen: dairy",
+            Allergen::Egg => "Allergen: egg",
+            Allergen::Gluten => "Allergen: gluten",
+            Allergen::Shellfish => "Allergen: shellfish",
+            Allergen::Fish => "Allergen: fish",
+            Allergen::Soy => "Allergen: soy",
+            Allergen::TreeNut => "Allergen: tree nut",
+            Allergen::Peanut => "Allergen: peanut",
+            Allergen::Sesame => "Allergen: sesame",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Allergen::ALL.into_iter().find(|a| a.id() == id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IngredientAllergens {
+    pub id: IngredientId,
+    pub allergens: &'static [Allergen],
+}
+
+const fn entry(id: IngredientId, allergens: &'static [Allergen]) -> IngredientAllergens {
+    IngredientAllergens { id, allergens }
+}
+
+pub static INGREDIENT_ALLERGENS: &[IngredientAllergens] = &[
+    // Proteins
+    entry(chicken_breast, &[]),
+    entry(chicken_thighs, &[]),
+    entry(drumsticks, &[]),
+    entry(steak, &[]),
+    entry(ground_beef, &[]),
+    entry(ground_turkey, &[]),
+    entry(pork_chops, &[]),
+    entry(pork_tenderloin, &[]),
+    entry(sausage, &[]),
+    entry(salmon, &[Fish]),
+    entry(shrimp, &[Shellfish]),
+    entry(tuna, &[Fish]),
+    entry(white_fish, &[Fish]),
+    entry(eggs, &[Egg]),
+    entry(bacon, &[]),
+    // Starches
+    entry(jasmine_rice, &[]),
+    entry(cilantro_lime_rice, &[]),
+    entry(fried_rice, &[Egg, Gluten, Soy, Sesame]),
+    entry(rice_pilaf, &[Gluten]),
+    entry(black_beans_rice, &[]),
+    entry(mashed_potatoes, &[Dairy]),
+    entry(roasted_potatoes, &[]),
+    entry(baked_potato, &[]),
+    entry(twice_baked_potato, &[Dairy]),
+    entry(au_gratin_potatoes, &[Dairy, Gluten]),
+    entry(hash_browns, &[]),
+    entry(sweet_potato, &[]),
+    entry(fries, &[]),
+    entry(bread_rolls, &[Dairy, Gluten]),
+    entry(garlic_bread, &[Dairy, Gluten]),
+    entry(naan, &[Dairy, Gluten]),
+    entry(tortillas, &[Gluten]),
+    entry(cornbread, &[Dairy, Egg, Gluten]),
+    entry(pasta, &[Gluten]),
+    entry(egg_noodles, &[Egg, Gluten]),
+    entry(couscous, &[Gluten]),
+    entry(quinoa, &[]),
+    entry(mac_cheese, &[Dairy, Gluten]),
+    entry(yellow_rice, &[]),
+    entry(polenta, &[Dairy]),
+    entry(stuffing, &[Dairy, Gluten]),
+    entry(pita, &[Gluten]),
+    entry(biscuits, &[Dairy, Gluten]),
+    entry(mexican_rice, &[]),
+    entry(creamed_corn, &[Dairy]),
+    // Vegetables
+    entry(broccoli, &[]),
+    entry(green_beans, &[]),
+    entry(mixed_salad, &[]),
+    entry(corn, &[]),
+    entry(carrots, &[]),
+    entry(asparagus, &[]),
+    entry(zucchini, &[]),
+    entry(roasted_tomatoes, &[]),
+    entry(brussels_sprouts, &[]),
+    entry(cauliflower, &[]),
+    entry(spinach, &[]),
+    entry(mushrooms, &[]),
+    entry(eggplant, &[]),
+    entry(bell_peppers, &[]),
+    entry(elote_corn, &[Dairy, Egg]),
+    entry(bok_choy, &[]),
+    entry(sugar_snap_peas, &[]),
+    entry(edamame, &[Soy]),
+    entry(bean_sprouts, &[]),
+    entry(cabbage_slaw, &[Sesame]),
+    entry(cucumber_dill, &[Dairy]),
+    entry(greek_salad, &[Dairy]),
+    entry(coleslaw, &[Egg]),
+    entry(collard_greens, &[]),
+    entry(baked_beans, &[]),
+    entry(kale, &[]),
+    entry(peas, &[]),
+    entry(cucumber_tomato, &[]),
+    entry(roasted_squash, &[]),
+    entry(grilled_onions, &[]),
+    entry(roasted_beets, &[]),
+    entry(sauteed_cabbage, &[]),
+    entry(pickled_vegetables, &[]),
+    entry(avocado_salad, &[]),
+    entry(black_eyed_peas, &[]),
+    entry(succotash, &[]),
+    entry(ratatouille, &[]),
+    entry(caesar_salad, &[Dairy, Egg, Fish, Gluten]),
+    entry(garden_salad, &[]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::assert_covers;
+
+    #[test]
+    fn every_ingredient_has_allergens() {
+        assert_covers(INGREDIENT_ALLERGENS.iter().map(|a| a.id), "IngredientAllergens", |_| true);
+    }
+}
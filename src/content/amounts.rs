@@ -0,0 +1,68 @@
+// Amounts — quantities in the units `buy_amount` is written in
+//
+// A dinner for four uses one whole `buy_amount` ("2.5 lbs", "12 drumsticks",
+// "1 dozen"), so stock counted in the same unit reconciles with both buying
+// and cooking. Items without a `buy_amount` are counted in meals.
+
+use std::fmt;
+
+use super::meal_data::Ingredient;
+
+/// Unit for items the catalog gives no `buy_amount`.
+pub const MEAL_UNIT: &str = "meal";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Amount {
+    pub qty: f64,
+    /// Singular form: "lb", "drumstick", "dozen".
+    pub unit: &'static str,
+}
+
+impl Amount {
+    pub fn is_meals(self) -> bool {
+        self.unit == MEAL_UNIT
+    }
+
+    /// The unit as it reads after this quantity: "lb" or "lbs".
+    pub fn unit_label(self) -> String {
+        let one = (self.qty - 1.0).abs() < f64::EPSILON;
+        let plural = if one || self.unit == "dozen" { "" } else { "s" };
+        format!("{}{plural}", self.unit)
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let qty = (self.qty * 100.0).round() / 100.0;
+        write!(f, "{qty} {}", self.unit_label())
+    }
+}
+
+/// Reads "2.5 lbs" as 2.5 lb; `None` when there's no leading number.
+pub fn parse_amount(s: &'static str) -> Option<Amount> {
+    let (qty, unit) = s.trim().split_once(' ')?;
+    let qty: f64 = qty.parse().ok().filter(|q: &f64| *q > 0.0)?;
+    let unit = unit.trim();
+    Some(Amount { qty, unit: unit.strip_suffix('s').unwrap_or(unit) })
+}
+
+/// What one dinner uses of `i`.
+pub fn meal_amount(i: &Ingredient) -> Amount {
+    i.buy_amount.and_then(parse_amount).unwrap_or(Amount { qty: 1.0, unit: MEAL_UNIT })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::meal_data::INGREDIENTS;
+
+    /// Every built-in `buy_amount` should read as a quantity and a unit.
+    #[test]
+    fn every_buy_amount_parses() {
+        for i in INGREDIENTS {
+            if let Some(b) = i.buy_amount {
+                assert!(parse_amount(b).is_some(), "{} has an unreadable buy_amount: {b}", i.id.as_str());
+            }
+        }
+    }
+}
@@ -0,0 +1,341 @@
+// Archetypes — named plate patterns that keep a meal reading as one dish
+//
+// Constraints name tags from meta.rs. `*_must_have_any`, `*_avoid_any` and
+// `max_cold_sides` are hard; `veg_prefer_any` only adds to the plate score in
+// the quality engine. See docs/spec.md §5.1, §6.3 and §7.
+
+use super::meal_data::Cuisine;
+use super::meta::IngredientMeta;
+use Cuisine::{American, Asian, Bbq, Latin, Mediterranean};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ArchetypeConstraints {
+    /// Protein tags the archetype is built around; empty accepts any protein.
+    pub protein_any: &'static [&'static str],
+    pub starch_must_have_any: &'static [&'static str],
+    pub starch_avoid_any: &'static [&'static str],
+    pub veg_prefer_any: &'static [&'static str],
+    pub veg_avoid_any: &'static [&'static str],
+    pub max_cold_sides: u8,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Archetype {
+    pub id: &'static str,
+    pub cuisine: Cuisine,
+    /// Shown in the UI badge.
+    pub label: &'static str,
+    pub constraints: ArchetypeConstraints,
+}
+
+fn has_any(m: Option<&IngredientMeta>, tags: &[&str]) -> bool {
+    m.is_some_and(|m| tags.iter().any(|t| m.has_tag(t)))
+}
+
+impl Archetype {
+    pub fn allows_protein(&self, m: Option<&IngredientMeta>) -> bool {
+        let c = &self.constraints;
+        c.protein_any.is_empty() || has_any(m, c.protein_any)
+    }
+
+    pub fn allows_starch(&self, m: Option<&IngredientMeta>) -> bool {
+        let c = &self.constraints;
+        (c.starch_must_have_any.is_empty() || has_any(m, c.starch_must_have_any))
+            && !has_any(m, c.starch_avoid_any)
+    }
+
+    pub fn allows_veg(&self, m: Option<&IngredientMeta>) -> bool {
+        !has_any(m, self.constraints.veg_avoid_any)
+    }
+
+    pub fn prefers_veg(&self, m: Option<&IngredientMeta>) -> bool {
+        has_any(m, self.constraints.veg_prefer_any)
+    }
+}
+
+const ANY: &[&str] = &[];
+
+pub static ARCHETYPES: &[Archetype] = &[
+    // Asian
+    Archetype {
+        id: "fried_rice_bowl",
+        cuisine: Asian,
+        label: "Fried Rice Bowl",
+        constraints: ArchetypeConstraints {
+            protein_any: ANY,
+            starch_must_have_any: &["starch_fried_rice"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["east_asian_signal", "hot_veg"],
+            veg_avoid_any: &["southern_signal"],
+            max_cold_sides: 1,
+        },
+    },
+    Archetype {
+        id: "noodle_stir_fry",
+        cuisine: Asian,
+        label: "Noodle Stir-Fry",
+        constraints: ArchetypeConstraints {
+            protein_any: ANY,
+            starch_must_have_any: &["starch_noodle"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["east_asian_signal"],
+            veg_avoid_any: &["legume_side", "southern_signal"],
+            max_cold_sides: 0,
+        },
+    },
+    Archetype {
+        id: "rice_and_greens",
+        cuisine: Asian,
+        label: "Rice & Greens",
+        constraints: ArchetypeConstraints {
+            protein_any: ANY,
+            starch_must_have_any: &["starch_rice"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["leafy_green", "hot_veg"],
+            veg_avoid_any: &["southern_signal"],
+            max_cold_sides: 1,
+        },
+    },
+    Archetype {
+        id: "south_asian_plate",
+        cuisine: Asian,
+        label: "South Asian Plate",
+        constraints: ArchetypeConstraints {
+            protein_any: &["protein_poultry", "protein_seafood"],
+            starch_must_have_any: &["south_asian_signal"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["hot_veg"],
+            veg_avoid_any: &["east_asian_signal"],
+            max_cold_sides: 1,
+        },
+    },
+    // Latin / Mexican
+    Archetype {
+        id: "tacos",
+        cuisine: Latin,
+        label: "Tacos",
+        constraints: ArchetypeConstraints {
+            protein_any: &["ground_meat", "protein_seafood", "protein_poultry", "protein_beef"],
+            starch_must_have_any: &["tortilla"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["cold_side"],
+            veg_avoid_any: &["legume_side"],
+            max_cold_sides: 1,
+        },
+    },
+    Archetype {
+        id: "fajita_plate",
+        cuisine: Latin,
+        label: "Fajita Plate",
+        constraints: ArchetypeConstraints {
+            protein_any: &["protein_poultry", "protein_beef", "protein_seafood"],
+            starch_must_have_any: &["tortilla"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["hot_veg"],
+            veg_avoid_any: ANY,
+            max_cold_sides: 1,
+        },
+    },
+    Archetype {
+        id: "beans_and_rice_plate",
+        cuisine: Latin,
+        label: "Beans & Rice Plate",
+        constraints: ArchetypeConstraints {
+            protein_any: ANY,
+            starch_must_have_any: &["starch_rice"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["hot_veg", "cold_side"],
+            veg_avoid_any: &["legume_side"],
+            max_cold_sides: 1,
+        },
+    },
+    Archetype {
+        id: "carne_asada_plate",
+        cuisine: Latin,
+        label: "Carne Asada Plate",
+        constraints: ArchetypeConstraints {
+            protein_any: &["protein_beef", "protein_poultry", "protein_pork"],
+            starch_must_have_any: &["starch_rice"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["hot_veg"],
+            veg_avoid_any: ANY,
+            max_cold_sides: 1,
+        },
+    },
+    // American
+    Archetype {
+        id: "steakhouse",
+        cuisine: American,
+        label: "Steakhouse",
+        constraints: ArchetypeConstraints {
+            protein_any: &["protein_beef", "protein_pork", "protein_seafood"],
+            starch_must_have_any: &["starch_potato"],
+            starch_avoid_any: &["starch_fried_rice"],
+            veg_prefer_any: &["hot_veg", "cold_side"],
+            veg_avoid_any: &["east_asian_signal", "legume_side"],
+            max_cold_sides: 1,
+        },
+    },
+    Archetype {
+        id: "sheet_pan_roast",
+        cuisine: American,
+        label: "Sheet-Pan Roast",
+        constraints: ArchetypeConstraints {
+            protein_any: ANY,
+            starch_must_have_any: &["starch_potato"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["hot_veg"],
+            veg_avoid_any: ANY,
+            max_cold_sides: 0,
+        },
+    },
+    Archetype {
+        id: "pasta_night",
+        cuisine: American,
+        label: "Pasta Night",
+        constraints: ArchetypeConstraints {
+            protein_any: &["protein_poultry", "protein_pork", "protein_seafood", "protein_beef"],
+            starch_must_have_any: &["starch_noodle"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["cold_side", "hot_veg"],
+            veg_avoid_any: &["legume_side"],
+            max_cold_sides: 1,
+        },
+    },
+    Archetype {
+        id: "breakfast_for_dinner",
+        cuisine: American,
+        label: "Breakfast for Dinner",
+        constraints: ArchetypeConstraints {
+            protein_any: &["protein_egg", "protein_pork"],
+            starch_must_have_any: &["starch_potato", "starch_bread"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["hot_veg"],
+            veg_avoid_any: ANY,
+            max_cold_sides: 1,
+        },
+    },
+    Archetype {
+        id: "comfort_bowl",
+        cuisine: American,
+        label: "Comfort Bowl",
+        constraints: ArchetypeConstraints {
+            protein_any: ANY,
+            starch_must_have_any: &["starch_noodle", "starch_baked"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["hot_veg"],
+            veg_avoid_any: ANY,
+            max_cold_sides: 0,
+        },
+    },
+    // BBQ / Comfort
+    Archetype {
+        id: "cookout_plate",
+        cuisine: Bbq,
+        label: "Cookout Plate",
+        constraints: ArchetypeConstraints {
+            protein_any: ANY,
+            starch_must_have_any: &["starch_bread"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["cold_side", "legume_side"],
+            veg_avoid_any: &["east_asian_signal"],
+            max_cold_sides: 1,
+        },
+    },
+    Archetype {
+        id: "southern_plate",
+        cuisine: Bbq,
+        label: "Southern Plate",
+        constraints: ArchetypeConstraints {
+            protein_any: ANY,
+            starch_must_have_any: &["starch_bread", "starch_baked"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["southern_signal", "leafy_green", "legume_side"],
+            veg_avoid_any: &["east_asian_signal"],
+            max_cold_sides: 1,
+        },
+    },
+    Archetype {
+        id: "bbq_smoke_plate",
+        cuisine: Bbq,
+        label: "Smokehouse Plate",
+        constraints: ArchetypeConstraints {
+            protein_any: &["protein_beef", "protein_pork", "protein_poultry"],
+            starch_must_have_any: &["starch_potato", "starch_baked"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["cold_side", "legume_side"],
+            veg_avoid_any: &["east_asian_signal"],
+            max_cold_sides: 1,
+        },
+    },
+    Archetype {
+        id: "seafood_cookout",
+        cuisine: Bbq,
+        label: "Seafood Cookout",
+        constraints: ArchetypeConstraints {
+            protein_any: &["protein_seafood"],
+            starch_must_have_any: &["starch_potato"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["corn_forward", "cold_side"],
+            veg_avoid_any: &["east_asian_signal"],
+            max_cold_sides: 1,
+        },
+    },
+    // Mediterranean
+    Archetype {
+        id: "grain_bowl",
+        cuisine: Mediterranean,
+        label: "Grain Bowl",
+        constraints: ArchetypeConstraints {
+            protein_any: ANY,
+            starch_must_have_any: &["starch_grain"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["hot_veg"],
+            veg_avoid_any: &["southern_signal"],
+            max_cold_sides: 1,
+        },
+    },
+    Archetype {
+        id: "mezze_plate",
+        cuisine: Mediterranean,
+        label: "Mezze Plate",
+        constraints: ArchetypeConstraints {
+            protein_any: ANY,
+            starch_must_have_any: &["starch_bread"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["cold_side"],
+            veg_avoid_any: &["southern_signal"],
+            max_cold_sides: 1,
+        },
+    },
+    Archetype {
+        id: "pasta_mediterranean",
+        cuisine: Mediterranean,
+        label: "Mediterranean Pasta",
+        constraints: ArchetypeConstraints {
+            protein_any: ANY,
+            starch_must_have_any: &["starch_noodle"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["tomato_forward", "hot_veg"],
+            veg_avoid_any: ANY,
+            max_cold_sides: 0,
+        },
+    },
+    Archetype {
+        id: "sheet_pan_mediterranean",
+        cuisine: Mediterranean,
+        label: "Mediterranean Sheet Pan",
+        constraints: ArchetypeConstraints {
+            protein_any: ANY,
+            starch_must_have_any: &["starch_potato"],
+            starch_avoid_any: ANY,
+            veg_prefer_any: &["hot_veg"],
+            veg_avoid_any: ANY,
+            max_cold_sides: 0,
+        },
+    },
+];
+
+pub fn archetypes_for(cuisine: Cuisine) -> impl Iterator<Item = &'static Archetype> {
+    ARCHETYPES.iter().filter(move |a| a.cuisine == cuisine)
+}
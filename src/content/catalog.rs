@@ -0,0 +1,178 @@
+// Active Catalog — built-in data merged with any loaded content packs
+
+use std::sync::{OnceLock, RwLock};
+
+use super::allergens::{IngredientAllergens, INGREDIENT_ALLERGENS};
+use super::diet::{IngredientDiet, INGREDIENT_DIET};
+use super::meta::{clashes, IngredientMeta, INGREDIENT_META};
+use super::nutrition::{IngredientNutrition, Nutrition, INGREDIENT_NUTRITION};
+use super::prices::{IngredientPrice, INGREDIENT_PRICES};
+use super::seasons::{IngredientSeason, INGREDIENT_SEASONS};
+use super::times::{IngredientTime, Minutes, INGREDIENT_TIMES, METHOD_TIMES};
+use super::weights::{IngredientWeight, PairWeight, DEFAULT_WEIGHT, INGREDIENT_WEIGHTS, PAIR_WEIGHTS};
+use super::meal_data::{Category, Cuisine, Ingredient, IngredientId, CUISINES, INGREDIENTS};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CuisineInfo {
+    pub cuisine: Cuisine,
+    pub label: &'static str,
+    pub sauces: &'static [&'static str],
+}
+
+/// Everything generation reads from. Pack entries are leaked when a pack is
+/// merged so the generator can keep handing out `&'static Ingredient`; packs
+/// are loaded a handful of times per session, so the cost is bounded.
+#[derive(Clone, Debug)]
+pub struct Catalog {
+    pub ingredients: Vec<&'static Ingredient>,
+    pub cuisines: Vec<CuisineInfo>,
+    pub diets: Vec<IngredientDiet>,
+    pub allergens: Vec<IngredientAllergens>,
+    pub nutrition: Vec<IngredientNutrition>,
+    pub meta: Vec<IngredientMeta>,
+    pub weights: Vec<IngredientWeight>,
+    pub pair_weights: Vec<PairWeight>,
+    pub prices: Vec<IngredientPrice>,
+    pub times: Vec<IngredientTime>,
+    pub seasons: Vec<IngredientSeason>,
+    pub packs: Vec<String>,
+}
+
+impl Catalog {
+    pub fn builtin() -> Self {
+        Catalog {
+            ingredients: INGREDIENTS.iter().collect(),
+            cuisines: CUISINES
+                .iter()
+                .map(|&c| CuisineInfo { cuisine: c, label: c.label(), sauces: c.sauces() })
+                .collect(),
+            diets: INGREDIENT_DIET.to_vec(),
+            allergens: INGREDIENT_ALLERGENS.to_vec(),
+            nutrition: INGREDIENT_NUTRITION.to_vec(),
+            meta: INGREDIENT_META.to_vec(),
+            weights: INGREDIENT_WEIGHTS.to_vec(),
+            pair_weights: PAIR_WEIGHTS.to_vec(),
+            prices: INGREDIENT_PRICES.to_vec(),
+            times: INGREDIENT_TIMES.to_vec(),
+            seasons: INGREDIENT_SEASONS.to_vec(),
+            packs: Vec::new(),
+        }
+    }
+
+    pub fn by_category(&self, cat: Category) -> Vec<&'static Ingredient> {
+        self.ingredients.iter().filter(|i| i.category == cat).copied().collect()
+    }
+
+    pub fn cuisine_list(&self) -> Vec<Cuisine> {
+        self.cuisines.iter().map(|c| c.cuisine).collect()
+    }
+
+    pub fn label(&self, c: Cuisine) -> &'static str {
+        self.cuisines.iter().find(|i| i.cuisine == c).map_or(c.label(), |i| i.label)
+    }
+
+    pub fn diet(&self, id: IngredientId) -> Option<&IngredientDiet> {
+        self.diets.iter().find(|d| d.id == id)
+    }
+
+    pub fn allergens(&self, id: IngredientId) -> Option<&IngredientAllergens> {
+        self.allergens.iter().find(|a| a.id == id)
+    }
+
+    pub fn nutrition(&self, id: IngredientId) -> Option<Nutrition> {
+        self.nutrition.iter().find(|n| n.id == id).map(|n| n.per_serving)
+    }
+
+    pub fn meta(&self, id: IngredientId) -> Option<&IngredientMeta> {
+        self.meta.iter().find(|m| m.id == id)
+    }
+
+    /// Relative odds of `id` coming up in its slot.
+    pub fn weight(&self, id: IngredientId) -> u32 {
+        self.weights.iter().find(|w| w.id == id).map_or(DEFAULT_WEIGHT, |w| w.weight)
+    }
+
+    /// Cents for one `buy_amount` of `id` (or one dinner's worth).
+    pub fn price(&self, id: IngredientId) -> Option<u32> {
+        self.prices.iter().find(|p| p.id == id).map(|p| p.cents)
+    }
+
+    /// Minutes for `id`, cooked the way `archetype` does it when that
+    /// differs from the item's usual method.
+    pub fn minutes(&self, id: IngredientId, archetype: Option<&str>) -> Option<Minutes> {
+        METHOD_TIMES
+            .iter()
+            .find(|m| m.id == id && Some(m.archetype) == archetype)
+            .map(|m| m.minutes)
+            .or_else(|| self.times.iter().find(|t| t.id == id).map(|t| t.minutes))
+    }
+
+    /// Whether `id` is in season in `month` (1–12). Items without a
+    /// season, and any month while the date isn't known (0), count as in.
+    pub fn in_season(&self, id: IngredientId, month: u32) -> bool {
+        self.seasons.iter().find(|s| s.id == id).is_none_or(|s| s.months.contains(month))
+    }
+
+    /// Percentage applied when `a` and `b` share a plate; 100 when the
+    /// pair isn't listed.
+    pub fn pair_weight(&self, a: IngredientId, b: IngredientId) -> u32 {
+        self.pair_weights
+            .iter()
+            .find(|p| (p.a == a && p.b == b) || (p.a == b && p.b == a))
+            .map_or(100, |p| p.weight)
+    }
+
+    /// Whether two ingredients are redundant or clash on one plate. Items
+    /// without meta never clash.
+    pub fn clashes(&self, a: IngredientId, b: IngredientId) -> bool {
+        match (self.meta(a), self.meta(b)) {
+            (Some(ma), Some(mb)) => clashes(ma, mb),
+            _ => false,
+        }
+    }
+
+    pub fn sauces(&self, c: Cuisine) -> &'static [&'static str] {
+        self.cuisines.iter().find(|i| i.cuisine == c).map_or(c.sauces(), |i| i.sauces)
+    }
+}
+
+static BUILTIN: OnceLock<Catalog> = OnceLock::new();
+static ACTIVE: RwLock<Option<&'static Catalog>> = RwLock::new(None);
+
+fn builtin_catalog() -> &'static Catalog {
+    BUILTIN.get_or_init(Catalog::builtin)
+}
+
+/// The catalog generation currently runs against.
+pub fn catalog() -> &'static Catalog {
+    ACTIVE
+        .read()
+        .ok()
+        .and_then(|active| *active)
+        .unwrap_or_else(builtin_catalog)
+}
+
+pub fn install_catalog(c: Catalog) {
+    let leaked: &'static Catalog = Box::leak(Box::new(c));
+    if let Ok(mut active) = ACTIVE.write() {
+        *active = Some(leaked);
+    }
+}
+
+pub fn reset_catalog() {
+    if let Ok(mut active) = ACTIVE.write() {
+        *active = None;
+    }
+}
+
+pub fn get_proteins() -> Vec<&'static Ingredient> {
+    catalog().by_category(Category::Protein)
+}
+
+pub fn get_starches() -> Vec<&'static Ingredient> {
+    catalog().by_category(Category::Starch)
+}
+
+pub fn get_vegs() -> Vec<&'static Ingredient> {
+    catalog().by_category(Category::Veg)
+}
@@ -0,0 +1,307 @@
+// Dietary Attributes — per-ingredient diet data, parallel to INGREDIENTS
+//
+// Entries describe the dish as it is usually cooked (collards with ham hock,
+// Caesar with anchovy), so a filter errs on the side of excluding.
+
+#[allow(clippy::wildcard_imports)]
+use super::meal_data::ids::*;
+use serde::{Deserialize, Serialize};
+
+use super::meal_data::IngredientId;
+use Contains::{Dairy, Egg, Gluten};
+use Flesh::{Meat, Meatless, Seafood};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Flesh {
+    Meatless,
+    Seafood,
+    Meat,
+}
+
+impl Flesh {
+    pub fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "meatless" => Some(Meatless),
+            "seafood" => Some(Seafood),
+            "meat" => Some(Meat),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Contains {
+    Dairy,
+    Egg,
+    Gluten,
+}
+
+impl Contains {
+    pub fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "dairy" => Some(Dairy),
+            "egg" => Some(Egg),
+            "gluten" => Some(Gluten),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IngredientDiet {
+    pub id: IngredientId,
+    pub flesh: Flesh,
+    pub contains: &'static [Contains],
+}
+
+impl IngredientDiet {
+    pub fn has(&self, c: Contains) -> bool {
+        self.contains.contains(&c)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Lifestyle {
+    #[default]
+    Omnivore,
+    Pescatarian,
+    Vegetarian,
+    Vegan,
+}
+
+impl Lifestyle {
+    pub const ALL: [Lifestyle; 4] =
+        [Lifestyle::Omnivore, Lifestyle::Pescatarian, Lifestyle::Vegetarian, Lifestyle::Vegan];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Lifestyle::Omnivore => "Anything",
+            Lifestyle::Pescatarian => "Pescatarian",
+            Lifestyle::Vegetarian => "Vegetarian",
+            Lifestyle::Vegan => "Vegan",
+        }
+    }
+}
+
+/// The household's dietary filter. Ingredients with no diet entry (pack
+/// items that don't declare one) only pass an unrestricted filter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DietFilter {
+    pub lifestyle: Lifestyle,
+    pub gluten_free: bool,
+    pub dairy_free: bool,
+}
+
+impl DietFilter {
+    pub fn is_restricted(self) -> bool {
+        self != DietFilter::default()
+    }
+
+    /// Short name for notices, e.g. "vegetarian, gluten-free".
+    pub fn describe(self) -> String {
+        let mut parts = Vec::new();
+        if self.lifestyle != Lifestyle::Omnivore {
+            parts.push(self.lifestyle.label().to_lowercase());
+        }
+        if self.gluten_free {
+            parts.push("gluten-free".to_string());
+        }
+        if self.dairy_free {
+            parts.push("dairy-free".to_string());
+        }
+        parts.join(", ")
+    }
+
+    /// Why `diet` fails this filter, or `None` when it passes.
+    pub fn violation(self, diet: Option<&IngredientDiet>) -> Option<&'static str> {
+        if !self.is_restricted() {
+            return None;
+        }
+        let Some(d) = diet else {
+            return Some("No dietary info");
+        };
+        match (self.lifestyle, d.flesh) {
+            (Lifestyle::Omnivore, _) | (Lifestyle::Pescatarian, Meatless | Seafood) | (_, Meatless) => {}
+            (_, Seafood) => return Some("Contains fish"),
+            (_, Meat) => return Some("Contains meat"),
+        }
+        if self.lifestyle == Lifestyle::Vegan && d.has(Egg) {
+            return Some("Contains egg");
+        }
+        if (self.lifestyle == Lifestyle::Vegan || self.dairy_free) && d.has(Dairy) {
+            return Some("Contains dairy");
+        }
+        if self.gluten_free && d.has(Gluten) {
+            return Some("Contains gluten");
+        }
+        None
+    }
+}
+
+const fn entry(id: IngredientId, flesh: Flesh, contains: &'static [Contains]) -> IngredientDiet {
+    IngredientDiet { id, flesh, contains }
+}
+
+pub static INGREDIENT_DIET: &[IngredientDiet] = &[
+    // Proteins
+    entry(chicken_breast, Meat, &[]),
+    entry(chicken_thighs, Meat, &[]),
+    entry(drumsticks, Meat, &[]),
+    entry(steak, Meat, &[]),
+    entry(ground_beef, Meat, &[]),
+    entry(ground_turkey, Meat, &[]),
+    entry(pork_chops, Meat, &[]),
+    entry(pork_tenderloin, Meat, &[]),
+    entry(sausage, Meat, &[]),
+    entry(salmon, Seafood, &[]),
+    entry(shrimp, Seafood, &[]),
+    entry(tuna, Seafood, &[]),
+    entry(white_fish, Seafood, &[]),
+    entry(eggs, Meatless, &[Egg]),
+    entry(bacon, Meat, &[]),
+    // Starches
+    entry(jasmine_rice, Meatless, &[]),
+    entry(cilantro_lime_rice, Meatless, &[]),
+    entry(fried_rice, Meatless, &[Egg, Gluten]),
+    entry(rice_pilaf, Meatless, &[Gluten]),
+    entry(black_beans_rice, Meatless, &[]),
+    entry(mashed_potatoes, Meatless, &[Dairy]),
+    entry(roasted_potatoes, Meatless, &[]),
+    entry(baked_potato, Meatless, &[]),
+    entry(twice_baked_potato, Meatless, &[Dairy]),
+    entry(au_gratin_potatoes, Meatless, &[Dairy, Gluten]),
+    entry(hash_browns, Meatless, &[]),
+    entry(sweet_potato, Meatless, &[]),
+    entry(fries, Meatless, &[]),
+    entry(bread_rolls, Meatless, &[Dairy, Gluten]),
+    entry(garlic_bread, Meatless, &[Dairy, Gluten]),
+    entry(naan, Meatless, &[Dairy, Gluten]),
+    entry(tortillas, Meatless, &[Gluten]),
+    entry(cornbread, Meatless, &[Dairy, Egg, Gluten]),
+    entry(pasta, Meatless, &[Gluten]),
+    entry(egg_noodles, Meatless, &[Egg, Gluten]),
+    entry(couscous, Meatless, &[Gluten]),
+    entry(quinoa, Meatless, &[]),
+    entry(mac_cheese, Meatless, &[Dairy, Gluten]),
+    entry(yellow_rice, Meatless, &[]),
+    entry(polenta, Meatless, &[Dairy]),
+    entry(stuffing, Meatless, &[Dairy, Gluten]),
+    entry(pita, Meatless, &[Gluten]),
+    entry(biscuits, Meatless, &[Dairy, Gluten]),
+    entry(mexican_rice, Meatless, &[]),
+    entry(creamed_corn, Meatless, &[Dairy]),
+    // Vegetables
+    entry(broccoli, Meatless, &[]),
+    entry(green_beans, Meatless, &[]),
+    entry(mixed_salad, Meatless, &[]),
+    entry(corn, Meatless, &[]),
+    entry(carrots, Meatless, &[]),
+    entry(asparagus, Meatless, &[]),
+    entry(zucchini, Meatless, &[]),
+    entry(roasted_tomatoes, Meatless, &[]),
+    entry(brussels_sprouts, Meatless, &[]),
+    entry(cauliflower, Meatless, &[]),
+    entry(spinach, Meatless, &[]),
+    entry(mushrooms, Meatless, &[]),
+    entry(eggplant, Meatless, &[]),
+    entry(bell_peppers, Meatless, &[]),
+    entry(elote_corn, Meatless, &[Dairy, Egg]),
+    entry(bok_choy, Meatless, &[]),
+    entry(sugar_snap_peas, Meatless, &[]),
+    entry(edamame, Meatless, &[]),
+    entry(bean_sprouts, Meatless, &[]),
+    entry(cabbage_slaw, Meatless, &[]),
+    entry(cucumber_dill, Meatless, &[Dairy]),
+    entry(greek_salad, Meatless, &[Dairy]),
+    entry(coleslaw, Meatless, &[Egg]),
+    entry(collard_greens, Meat, &[]),
+    entry(baked_beans, Meat, &[]),
+    entry(kale, Meatless, &[]),
+    entry(peas, Meatless, &[]),
+    entry(cucumber_tomato, Meatless, &[]),
+    entry(roasted_squash, Meatless, &[]),
+    entry(grilled_onions, Meatless, &[]),
+    entry(roasted_beets, Meatless, &[]),
+    entry(sauteed_cabbage, Meatless, &[]),
+    entry(pickled_vegetables, Meatless, &[]),
+    entry(avocado_salad, Meatless, &[]),
+    entry(black_eyed_peas, Meatless, &[]),
+    entry(succotash, Meatless, &[]),
+    entry(ratatouille, Meatless, &[]),
+    entry(caesar_salad, Seafood, &[Dairy, Egg, Gluten]),
+    entry(garden_salad, Meatless, &[]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::assert_covers;
+
+    #[test]
+    fn every_ingredient_has_a_diet() {
+        assert_covers(INGREDIENT_DIET.iter().map(|d| d.id), "IngredientDiet", |_| true);
+    }
+
+    fn check(filter: DietFilter, id: IngredientId) -> Option<&'static str> {
+        filter.violation(INGREDIENT_DIET.iter().find(|d| d.id == id))
+    }
+
+    fn living(lifestyle: Lifestyle) -> DietFilter {
+        DietFilter { lifestyle, ..DietFilter::default() }
+    }
+
+    #[test]
+    fn vegetarian_turns_away_meat_and_fish_but_not_eggs() {
+        let f = living(Lifestyle::Vegetarian);
+        assert_eq!(check(f, steak), Some("Contains meat"));
+        assert_eq!(check(f, salmon), Some("Contains fish"));
+        assert_eq!(check(f, eggs), None);
+        assert_eq!(check(f, mashed_potatoes), None);
+    }
+
+    #[test]
+    fn pescatarian_allows_seafood() {
+        let f = living(Lifestyle::Pescatarian);
+        assert_eq!(check(f, salmon), None);
+        assert_eq!(check(f, shrimp), None);
+        assert_eq!(check(f, chicken_breast), Some("Contains meat"));
+    }
+
+    #[test]
+    fn vegan_turns_away_egg_and_dairy() {
+        let f = living(Lifestyle::Vegan);
+        assert_eq!(check(f, eggs), Some("Contains egg"));
+        assert_eq!(check(f, mashed_potatoes), Some("Contains dairy"));
+        assert_eq!(check(f, broccoli), None);
+    }
+
+    #[test]
+    fn gluten_and_dairy_free_check_their_own_flags() {
+        let gluten_free = DietFilter { gluten_free: true, ..DietFilter::default() };
+        assert_eq!(check(gluten_free, pasta), Some("Contains gluten"));
+        assert_eq!(check(gluten_free, tortillas), Some("Contains gluten"));
+        assert_eq!(check(gluten_free, mashed_potatoes), None);
+        let dairy_free = DietFilter { dairy_free: true, ..DietFilter::default() };
+        assert_eq!(check(dairy_free, mac_cheese), Some("Contains dairy"));
+        assert_eq!(check(dairy_free, pasta), None);
+    }
+
+    /// Collards are braised with ham hock and Caesar dressing carries
+    /// anchovy, so neither is vegetarian.
+    #[test]
+    fn collards_count_as_meat_and_caesar_as_fish() {
+        let vegetarian = living(Lifestyle::Vegetarian);
+        assert_eq!(check(vegetarian, collard_greens), Some("Contains meat"));
+        assert_eq!(check(vegetarian, caesar_salad), Some("Contains fish"));
+        assert_eq!(check(living(Lifestyle::Pescatarian), caesar_salad), None);
+    }
+
+    #[test]
+    fn only_a_restricted_filter_needs_data() {
+        assert_eq!(DietFilter::default().violation(None), None);
+        assert_eq!(check(DietFilter::default(), steak), None);
+        assert_eq!(living(Lifestyle::Vegan).violation(None), Some("No dietary info"));
+    }
+}
@@ -0,0 +1,68 @@
+// Leftovers — what a batch cooked one night turns into the next
+//
+// Cooking a double batch of `from` leaves a second dinner's worth that comes
+// back the following night as `into`, cooked `cuisine`'s way: chicken
+// thighs shredded into Latin tortillas, jasmine rice fried for an Asian
+// plate. `into` is often the same item in another cuisine; it's always in
+// the same category as `from` and listed under `cuisine`.
+
+#[allow(clippy::wildcard_imports)]
+use super::meal_data::ids::*;
+use super::meal_data::{Cuisine, IngredientId};
+use Cuisine::{American, Asian, Latin};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    pub from: IngredientId,
+    pub into: IngredientId,
+    pub cuisine: Cuisine,
+    /// Finishes "Monday's chicken thighs, …".
+    pub note: &'static str,
+}
+
+const fn transform(from: IngredientId, into: IngredientId, cuisine: Cuisine, note: &'static str) -> Transform {
+    Transform { from, into, cuisine, note }
+}
+
+pub static TRANSFORMS: &[Transform] = &[
+    // Proteins
+    transform(chicken_thighs, chicken_thighs, Latin, "shredded into tortillas"),
+    transform(chicken_breast, chicken_breast, Latin, "sliced for fajitas"),
+    transform(steak, steak, Latin, "sliced thin for tacos"),
+    transform(ground_beef, ground_beef, Latin, "seasoned as taco meat"),
+    transform(pork_tenderloin, pork_tenderloin, Asian, "sliced into a stir-fry"),
+    transform(salmon, salmon, Asian, "flaked over rice"),
+    // Starches
+    transform(jasmine_rice, fried_rice, Asian, "fried \u{2014} day-old rice fries best"),
+    transform(baked_potato, hash_browns, American, "grated into hash browns"),
+    transform(roasted_potatoes, hash_browns, American, "crisped into hash browns"),
+];
+
+/// What a batch of `id` can become the next night.
+pub fn transforms_from(id: IngredientId) -> impl Iterator<Item = &'static Transform> {
+    TRANSFORMS.iter().filter(move |t| t.from == id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::meal_data::INGREDIENTS;
+
+    /// Each transform has to land on a plate the generator could build.
+    #[test]
+    fn transforms_stay_in_category_and_cuisine() {
+        let find = |id: IngredientId| INGREDIENTS.iter().find(|i| i.id == id);
+        for t in TRANSFORMS {
+            let (Some(from), Some(into)) = (find(t.from), find(t.into)) else {
+                panic!("Transform names an unknown item: {}", t.from.as_str());
+            };
+            assert!(from.category == into.category, "Transform changes category: {}", t.from.as_str());
+            assert!(
+                into.cuisines.iter().any(|(c, _)| *c == t.cuisine),
+                "Transform target {} isn't listed under {}",
+                t.into.as_str(),
+                t.cuisine.label()
+            );
+        }
+    }
+}
@@ -0,0 +1,315 @@
+// Content Lint — consistency checks over the pairing graph
+//
+// Errors are things that break generation or the catalog's invariants;
+// warnings are editorial gaps worth a look before a data change ships.
+// Notes are left out of both counts: one-way pairings are a note, grouped
+// per ingredient, since generation reads a pairing in either direction.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+
+use super::amounts::parse_amount;
+use super::catalog::Catalog;
+use super::meal_data::{Category, Cuisine, Ingredient, IngredientId};
+
+pub const MIN_PROTEINS: usize = 3;
+pub const MIN_STARCHES: usize = 3;
+pub const MIN_VEGS: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Check {
+    InvalidPack,
+    DuplicateId,
+    UnknownId,
+    UntaggedReference,
+    AsymmetricEdge,
+    Unreachable,
+    ThinCuisine,
+    UnreadableAmount,
+}
+
+impl Check {
+    pub const fn id(self) -> &'static str {
+        match self {
+            Check::InvalidPack => "invalid_pack",
+            Check::DuplicateId => "duplicate_id",
+            Check::UnknownId => "unknown_id",
+            Check::UntaggedReference => "untagged_reference",
+            Check::AsymmetricEdge => "asymmetric_edge",
+            Check::Unreachable => "unreachable",
+            Check::ThinCuisine => "thin_cuisine",
+            Check::UnreadableAmount => "unreadable_amount",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Finding {
+    pub check: Check,
+    pub severity: Severity,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct LintReport {
+    pub ingredients: usize,
+    pub cuisines: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    pub notes: usize,
+    pub findings: Vec<Finding>,
+}
+
+impl LintReport {
+    pub fn error(&mut self, check: Check, message: String) {
+        self.errors += 1;
+        self.findings.push(Finding { check, severity: Severity::Error, message });
+    }
+
+    pub fn warn(&mut self, check: Check, message: String) {
+        self.warnings += 1;
+        self.findings.push(Finding { check, severity: Severity::Warning, message });
+    }
+
+    pub fn note(&mut self, check: Check, message: String) {
+        self.notes += 1;
+        self.findings.push(Finding { check, severity: Severity::Note, message });
+    }
+}
+
+pub fn lint_catalog(c: &Catalog, report: &mut LintReport) {
+    report.ingredients = c.ingredients.len();
+    report.cuisines = c.cuisines.len();
+
+    let by_id: HashMap<&str, &Ingredient> = c.ingredients.iter().map(|i| (i.id.as_str(), *i)).collect();
+
+    check_duplicates(c, report);
+    check_edges(c, &by_id, report);
+    check_reachability(c, report);
+    check_cuisine_sizes(c, report);
+    check_amounts(c, report);
+}
+
+fn pairs_in(i: &Ingredient, c: Cuisine) -> Option<&'static [IngredientId]> {
+    i.cuisines.iter().find(|(kc, _)| *kc == c).map(|(_, pairs)| *pairs)
+}
+
+fn check_duplicates(c: &Catalog, report: &mut LintReport) {
+    let mut seen = HashSet::new();
+    for i in &c.ingredients {
+        if !seen.insert(i.id) {
+            report.error(Check::DuplicateId, format!("{} is defined more than once", i.id.as_str()));
+        }
+    }
+}
+
+fn check_edges(c: &Catalog, by_id: &HashMap<&str, &Ingredient>, report: &mut LintReport) {
+    for a in &c.ingredients {
+        let mut one_way = Vec::new();
+        for (cuisine, pairs) in a.cuisines {
+            let cid = cuisine.id();
+            for b_id in *pairs {
+                let b_name = b_id.as_str();
+                let Some(b) = by_id.get(b_name) else {
+                    report.error(Check::UnknownId, format!("{} ({cid}) lists unknown ingredient {b_name}", a.id.as_str()));
+                    continue;
+                };
+                match pairs_in(b, *cuisine) {
+                    None => report.warn(
+                        Check::UntaggedReference,
+                        format!("{} ({cid}) lists {b_name}, which is not tagged for {cid}", a.id.as_str()),
+                    ),
+                    Some(back) if !back.contains(&a.id) => one_way.push(format!("{b_name} ({cid})")),
+                    Some(_) => {}
+                }
+            }
+        }
+        if !one_way.is_empty() {
+            let id = a.id.as_str();
+            report.note(Check::AsymmetricEdge, format!("{id} -> {}: none list {id} back", one_way.join(", ")));
+        }
+    }
+}
+
+/// A side is reachable when some protein pairs with it, in either direction,
+/// in some cuisine — the same rule `pairs_with_protein` applies.
+fn check_reachability(c: &Catalog, report: &mut LintReport) {
+    let cuisines = c.cuisine_list();
+    let proteins = c.by_category(Category::Protein);
+    for i in &c.ingredients {
+        let reachable = if i.category == Category::Protein {
+            i.cuisines.iter().any(|(kc, _)| cuisines.contains(kc))
+        } else {
+            proteins.iter().any(|p| {
+                cuisines.iter().any(|&cu| {
+                    pairs_in(p, cu).is_some_and(|pairs| pairs.contains(&i.id))
+                        || pairs_in(i, cu).is_some_and(|pairs| pairs.contains(&p.id))
+                })
+            })
+        };
+        if !reachable {
+            report.warn(Check::Unreachable, format!("{} can never be generated", i.id.as_str()));
+        }
+    }
+}
+
+fn check_cuisine_sizes(c: &Catalog, report: &mut LintReport) {
+    let minimums = [
+        (Category::Protein, "proteins", MIN_PROTEINS),
+        (Category::Starch, "starches", MIN_STARCHES),
+        (Category::Veg, "vegs", MIN_VEGS),
+    ];
+    for info in &c.cuisines {
+        for (cat, noun, min) in minimums {
+            let count = c
+                .by_category(cat)
+                .iter()
+                .filter(|i| pairs_in(i, info.cuisine).is_some())
+                .count();
+            let cid = info.cuisine.id();
+            if count == 0 {
+                report.error(Check::ThinCuisine, format!("{cid} has no {noun}"));
+            } else if count < min {
+                report.warn(Check::ThinCuisine, format!("{cid} has only {count} {noun} (minimum {min})"));
+            }
+        }
+    }
+}
+
+/// Pantry stock is counted in `buy_amount` units; one that doesn't read as
+/// "<qty> <unit>" falls back to counting meals.
+fn check_amounts(c: &Catalog, report: &mut LintReport) {
+    for i in &c.ingredients {
+        if let Some(b) = i.buy_amount.filter(|b| parse_amount(b).is_none()) {
+            let id = i.id.as_str();
+            report.warn(Check::UnreadableAmount, format!("{id} buy_amount \"{b}\" has no quantity and unit"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::catalog::CuisineInfo;
+    use crate::content::meal_data::ids::{chicken_breast, steak};
+
+    const TEST: Cuisine = Cuisine::Custom("test");
+
+    /// A veg side called `lint_side` with the given tags, for seeding one
+    /// finding at a time.
+    fn side(buy_amount: Option<&'static str>, cuisines: &'static [(Cuisine, &'static [IngredientId])]) -> Ingredient {
+        Ingredient { id: IngredientId("lint_side"), name: "Lint Side", category: Category::Veg, buy_amount, cuisines }
+    }
+
+    /// The built-in catalog with `extra` added.
+    fn with(extra: Ingredient) -> Catalog {
+        let mut c = Catalog::builtin();
+        c.ingredients.push(Box::leak(Box::new(extra)));
+        c
+    }
+
+    /// Severity of the `check` finding whose message mentions `about`.
+    fn fired(c: &Catalog, check: Check, about: &str) -> Option<Severity> {
+        let mut report = LintReport::default();
+        lint_catalog(c, &mut report);
+        report.findings.iter().find(|f| f.check == check && f.message.contains(about)).map(|f| f.severity)
+    }
+
+    #[test]
+    fn the_built_in_catalog_has_no_errors() {
+        let mut report = LintReport::default();
+        lint_catalog(&Catalog::builtin(), &mut report);
+        let errors: Vec<_> = report.findings.iter().filter(|f| f.severity == Severity::Error).collect();
+        assert!(errors.is_empty(), "{errors:?}");
+        assert_eq!(report.ingredients, Catalog::builtin().ingredients.len());
+    }
+
+    #[test]
+    fn a_well_formed_side_raises_nothing() {
+        let c = with(side(Some("1 lb"), &[(Cuisine::American, &[chicken_breast])]));
+        let mut report = LintReport::default();
+        lint_catalog(&c, &mut report);
+        let own: Vec<_> = report
+            .findings
+            .iter()
+            .filter(|f| f.message.contains("lint_side") && f.check != Check::AsymmetricEdge)
+            .collect();
+        assert!(own.is_empty(), "{own:?}");
+    }
+
+    #[test]
+    fn a_repeated_id_is_an_error() {
+        let mut c = Catalog::builtin();
+        c.ingredients.push(c.ingredients[0]);
+        let id = c.ingredients[0].id.as_str();
+        assert_eq!(fired(&c, Check::DuplicateId, id), Some(Severity::Error));
+    }
+
+    #[test]
+    fn an_unknown_pairing_is_an_error() {
+        let c = with(side(None, &[(Cuisine::American, &[IngredientId("no_such_item")])]));
+        assert_eq!(fired(&c, Check::UnknownId, "no_such_item"), Some(Severity::Error));
+    }
+
+    #[test]
+    fn a_pairing_outside_the_partners_cuisines_warns() {
+        let c = with(side(None, &[(TEST, &[chicken_breast])]));
+        assert_eq!(fired(&c, Check::UntaggedReference, "lint_side (test)"), Some(Severity::Warning));
+    }
+
+    #[test]
+    fn a_one_way_pairing_warns() {
+        let c = with(side(None, &[(Cuisine::American, &[chicken_breast])]));
+        assert_eq!(fired(&c, Check::AsymmetricEdge, "lint_side -> chicken_breast (american)"), Some(Severity::Note));
+    }
+
+    #[test]
+    fn one_way_pairings_are_one_note_per_ingredient() {
+        let c = with(side(None, &[(Cuisine::American, &[chicken_breast, steak]), (Cuisine::Latin, &[steak])]));
+        let mut report = LintReport::default();
+        lint_catalog(&c, &mut report);
+        let own: Vec<_> = report
+            .findings
+            .iter()
+            .filter(|f| f.check == Check::AsymmetricEdge && f.message.starts_with("lint_side"))
+            .collect();
+        assert_eq!(own.len(), 1, "{own:?}");
+        assert!(own[0].message.contains("chicken_breast (american), steak (american), steak (latin)"), "{}", own[0].message);
+        assert_eq!(report.notes, report.findings.iter().filter(|f| f.severity == Severity::Note).count());
+        assert_eq!(report.warnings, report.findings.iter().filter(|f| f.severity == Severity::Warning).count());
+    }
+
+    #[test]
+    fn a_side_no_protein_pairs_with_warns() {
+        let c = with(side(None, &[(Cuisine::American, &[])]));
+        assert_eq!(fired(&c, Check::Unreachable, "lint_side"), Some(Severity::Warning));
+    }
+
+    #[test]
+    fn an_empty_cuisine_is_an_error_and_a_thin_one_warns() {
+        let mut c = Catalog::builtin();
+        c.cuisines.push(CuisineInfo { cuisine: TEST, label: "Test", sauces: &[] });
+        assert_eq!(fired(&c, Check::ThinCuisine, "test has no proteins"), Some(Severity::Error));
+
+        let protein = Ingredient { category: Category::Protein, ..side(None, &[(TEST, &[])]) };
+        c.ingredients.push(Box::leak(Box::new(protein)));
+        assert_eq!(fired(&c, Check::ThinCuisine, "test has only 1 proteins"), Some(Severity::Warning));
+        assert_eq!(fired(&c, Check::ThinCuisine, "test has no proteins"), None);
+    }
+
+    #[test]
+    fn a_buy_amount_without_a_unit_warns() {
+        let c = with(side(Some("a bunch"), &[(Cuisine::American, &[chicken_breast])]));
+        assert_eq!(fired(&c, Check::UnreadableAmount, "lint_side"), Some(Severity::Warning));
+    }
+}
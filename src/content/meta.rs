@@ -0,0 +1,217 @@
+// Ingredient Meta — tags, exclusion groups and aliases, parallel to INGREDIENTS
+//
+// Tags are plain strings so archetype constraints and content packs can name
+// them without a code change; see docs/spec.md §5.2 for the vocabulary.
+
+#[allow(clippy::wildcard_imports)]
+use super::meal_data::ids::*;
+use super::meal_data::IngredientId;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IngredientMeta {
+    pub id: IngredientId,
+    pub tags: &'static [&'static str],
+    pub exclusion_groups: &'static [&'static str],
+    pub aliases: &'static [&'static str],
+}
+
+impl IngredientMeta {
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(&tag)
+    }
+}
+
+/// A plate holds at most one item carrying each of these tags.
+pub const REDUNDANT_TAGS: &[&str] = &["bitter_green", "tomato_forward", "corn_forward", "cabbage_forward"];
+
+/// Cuisine signals that read as two different dinners on one plate.
+pub const SIGNAL_CONFLICTS: &[(&str, &str)] = &[
+    ("south_asian_signal", "east_asian_signal"),
+    ("italian_american_signal", "southern_signal"),
+];
+
+/// Tier 1 of the quality model: true when `a` and `b` can't share a plate.
+pub fn clashes(a: &IngredientMeta, b: &IngredientMeta) -> bool {
+    a.exclusion_groups.iter().any(|g| b.exclusion_groups.contains(g))
+        || REDUNDANT_TAGS.iter().any(|t| a.has_tag(t) && b.has_tag(t))
+        || SIGNAL_CONFLICTS.iter().any(|(x, y)| {
+            (a.has_tag(x) && b.has_tag(y)) || (a.has_tag(y) && b.has_tag(x))
+        })
+}
+
+const fn meta(
+    id: IngredientId,
+    tags: &'static [&'static str],
+    exclusion_groups: &'static [&'static str],
+    aliases: &'static [&'static str],
+) -> IngredientMeta {
+    IngredientMeta { id, tags, exclusion_groups, aliases }
+}
+
+pub static INGREDIENT_META: &[IngredientMeta] = &[
+    // Proteins
+    meta(chicken_breast, &["protein_poultry"], &[], &["chicken", "chicken cutlet"]),
+    meta(chicken_thighs, &["protein_poultry"], &[], &["chicken thigh", "boneless thighs"]),
+    meta(drumsticks, &["protein_poultry"], &[], &["chicken legs", "drumstick"]),
+    meta(steak, &["protein_beef"], &[], &["ribeye", "sirloin", "strip steak"]),
+    meta(ground_beef, &["protein_beef", "ground_meat"], &[], &["hamburger", "minced beef"]),
+    meta(ground_turkey, &["protein_poultry", "ground_meat"], &[], &["turkey taco meat"]),
+    meta(pork_chops, &["protein_pork"], &[], &["pork chop", "bone-in chops"]),
+    meta(pork_tenderloin, &["protein_pork"], &[], &["pork loin", "tenderloin"]),
+    meta(sausage, &["protein_pork"], &[], &["italian sausage", "sausage links"]),
+    meta(salmon, &["protein_seafood"], &[], &["salmon fillet"]),
+    meta(shrimp, &["protein_seafood"], &[], &["prawns"]),
+    meta(tuna, &["protein_seafood"], &[], &["ahi", "tuna steak"]),
+    meta(white_fish, &["protein_seafood"], &[], &["tilapia", "cod", "white fish fillet"]),
+    meta(eggs, &["protein_egg"], &[], &["fried eggs", "scrambled eggs"]),
+    meta(bacon, &["protein_pork"], &[], &["thick-cut bacon"]),
+    // Starches
+    meta(jasmine_rice, &["starch_rice"], &[], &[]),
+    meta(cilantro_lime_rice, &["starch_rice"], &[], &[]),
+    meta(fried_rice, &["starch_fried_rice"], &[], &[]),
+    meta(rice_pilaf, &["starch_rice"], &[], &[]),
+    meta(black_beans_rice, &["starch_rice"], &[], &[]),
+    meta(mashed_potatoes, &["starch_potato"], &[], &[]),
+    meta(roasted_potatoes, &["starch_potato"], &[], &[]),
+    meta(baked_potato, &["starch_potato"], &[], &[]),
+    meta(twice_baked_potato, &["starch_potato"], &[], &[]),
+    meta(au_gratin_potatoes, &["starch_potato"], &[], &[]),
+    meta(hash_browns, &["starch_potato"], &[], &[]),
+    meta(sweet_potato, &["starch_potato"], &[], &[]),
+    meta(fries, &["starch_potato"], &[], &[]),
+    meta(bread_rolls, &["starch_bread"], &[], &[]),
+    meta(garlic_bread, &["starch_bread", "italian_american_signal"], &[], &[]),
+    meta(naan, &["starch_bread", "south_asian_signal"], &[], &[]),
+    meta(tortillas, &["starch_bread", "tortilla"], &[], &[]),
+    meta(cornbread, &["starch_bread", "southern_signal"], &[], &[]),
+    meta(pasta, &["starch_noodle", "italian_american_signal"], &[], &[]),
+    meta(egg_noodles, &["starch_noodle"], &[], &[]),
+    meta(couscous, &["starch_grain"], &[], &[]),
+    meta(quinoa, &["starch_grain"], &[], &[]),
+    meta(mac_cheese, &["starch_baked"], &[], &[]),
+    meta(yellow_rice, &["starch_rice"], &[], &[]),
+    meta(polenta, &["starch_grain"], &[], &[]),
+    meta(stuffing, &["starch_baked"], &[], &[]),
+    meta(pita, &["starch_bread"], &[], &[]),
+    meta(biscuits, &["starch_bread", "southern_signal"], &[], &[]),
+    meta(mexican_rice, &["starch_rice"], &[], &[]),
+    meta(creamed_corn, &["starch_corn", "corn_forward"], &["group_corn"], &[]),
+    // Vegetables
+    meta(broccoli, &["hot_veg"], &[], &[]),
+    meta(green_beans, &["hot_veg"], &[], &[]),
+    meta(mixed_salad, &["cold_side"], &["group_salad"], &[]),
+    meta(corn, &["hot_veg", "corn_forward"], &["group_corn"], &[]),
+    meta(carrots, &["hot_veg"], &[], &[]),
+    meta(asparagus, &["hot_veg"], &[], &[]),
+    meta(zucchini, &["hot_veg"], &[], &[]),
+    meta(roasted_tomatoes, &["hot_veg", "tomato_forward"], &["group_tomato_side"], &[]),
+    meta(brussels_sprouts, &["hot_veg", "bitter_green"], &["group_bitter_green"], &[]),
+    meta(cauliflower, &["hot_veg"], &[], &[]),
+    meta(spinach, &["hot_veg", "leafy_green"], &[], &[]),
+    meta(mushrooms, &["hot_veg"], &[], &[]),
+    meta(eggplant, &["hot_veg"], &[], &[]),
+    meta(bell_peppers, &["hot_veg"], &[], &[]),
+    meta(elote_corn, &["hot_veg", "corn_forward"], &["group_corn"], &[]),
+    meta(bok_choy, &["hot_veg", "leafy_green", "east_asian_signal"], &[], &[]),
+    meta(sugar_snap_peas, &["hot_veg"], &[], &[]),
+    meta(edamame, &["legume_side", "east_asian_signal"], &[], &[]),
+    meta(bean_sprouts, &["hot_veg", "east_asian_signal"], &[], &[]),
+    meta(cabbage_slaw, &["cold_side", "cabbage_forward"], &["group_cabbage"], &[]),
+    meta(cucumber_dill, &["cold_side"], &[], &[]),
+    meta(greek_salad, &["cold_side"], &[], &[]),
+    meta(coleslaw, &["cold_side", "cabbage_forward"], &["group_cabbage"], &[]),
+    meta(
+        collard_greens,
+        &["hot_veg", "leafy_green", "bitter_green", "southern_signal"],
+        &["group_bitter_green"],
+        &[],
+    ),
+    meta(baked_beans, &["legume_side"], &[], &[]),
+    meta(kale, &["hot_veg", "leafy_green", "bitter_green"], &["group_bitter_green"], &[]),
+    meta(peas, &["hot_veg"], &[], &[]),
+    meta(cucumber_tomato, &["cold_side", "tomato_forward"], &["group_tomato_side"], &[]),
+    meta(roasted_squash, &["hot_veg"], &[], &[]),
+    meta(grilled_onions, &["hot_veg"], &[], &[]),
+    meta(roasted_beets, &["hot_veg"], &[], &[]),
+    meta(sauteed_cabbage, &["hot_veg", "cabbage_forward"], &["group_cabbage"], &[]),
+    meta(pickled_vegetables, &["cold_side", "east_asian_signal"], &[], &[]),
+    meta(avocado_salad, &["cold_side"], &[], &[]),
+    meta(black_eyed_peas, &["legume_side", "southern_signal"], &[], &[]),
+    meta(succotash, &["hot_veg", "corn_forward"], &[], &[]),
+    meta(ratatouille, &["hot_veg", "stewed_veg", "tomato_forward"], &[], &[]),
+    meta(caesar_salad, &["cold_side"], &["group_salad"], &[]),
+    meta(garden_salad, &["cold_side"], &["group_salad"], &[]),
+];
+
+#[cfg(debug_assertions)]
+pub fn assert_meta_coverage() {
+    use super::meal_data::INGREDIENTS;
+    use std::collections::HashSet;
+    let meta_ids: HashSet<IngredientId> = INGREDIENT_META.iter().map(|m| m.id).collect();
+    for ing in INGREDIENTS {
+        debug_assert!(
+            meta_ids.contains(&ing.id),
+            "IngredientMeta missing entry for: {}", ing.id.as_str()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clash(a: IngredientId, b: IngredientId) -> bool {
+        let find = |id: IngredientId| {
+            INGREDIENT_META.iter().find(|m| m.id == id).unwrap_or_else(|| panic!("no meta for {}", id.as_str()))
+        };
+        clashes(find(a), find(b)) && clashes(find(b), find(a))
+    }
+
+    #[test]
+    fn group_corn_allows_one_corn_side() {
+        assert!(clash(corn, elote_corn));
+        assert!(clash(creamed_corn, corn));
+        assert!(!clash(corn, broccoli));
+    }
+
+    #[test]
+    fn group_salad_allows_one_salad() {
+        assert!(clash(mixed_salad, garden_salad));
+        assert!(clash(caesar_salad, garden_salad));
+        assert!(!clash(garden_salad, cucumber_dill));
+    }
+
+    #[test]
+    fn group_tomato_side_allows_one_tomato_side() {
+        assert!(clash(roasted_tomatoes, cucumber_tomato));
+        assert!(!clash(roasted_tomatoes, zucchini));
+    }
+
+    #[test]
+    fn group_bitter_green_allows_one_bitter_green() {
+        assert!(clash(kale, brussels_sprouts));
+        assert!(clash(collard_greens, kale));
+        assert!(!clash(kale, spinach));
+    }
+
+    #[test]
+    fn group_cabbage_allows_one_cabbage_side() {
+        assert!(clash(coleslaw, cabbage_slaw));
+        assert!(clash(sauteed_cabbage, coleslaw));
+        assert!(!clash(coleslaw, carrots));
+    }
+
+    #[test]
+    fn south_and_east_asian_signals_clash() {
+        assert!(clash(naan, bok_choy));
+        assert!(clash(naan, edamame));
+        assert!(!clash(naan, spinach));
+    }
+
+    #[test]
+    fn italian_american_and_southern_signals_clash() {
+        assert!(clash(pasta, collard_greens));
+        assert!(clash(garlic_bread, black_eyed_peas));
+        assert!(!clash(pasta, broccoli));
+    }
+}
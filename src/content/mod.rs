@@ -1,5 +1,45 @@
 // Content Module — The "CMS" Data Layer
 
+mod allergens;
+mod amounts;
+mod archetypes;
+mod catalog;
+mod diet;
+mod leftovers;
+mod lint;
 mod meal_data;
+mod meta;
+mod nutrition;
+mod pack;
+mod prices;
+mod seasons;
+mod times;
+mod weights;
 
-pub use meal_data::{get_proteins, get_starches, get_vegs, Ingredient, CUISINES, CUISINE_LABELS};
+pub use allergens::Allergen;
+pub use amounts::{meal_amount, Amount, MEAL_UNIT};
+pub use archetypes::{archetypes_for, Archetype};
+pub use catalog::{catalog, Catalog, get_proteins, get_starches, get_vegs, install_catalog, reset_catalog};
+pub use diet::{DietFilter, Lifestyle};
+pub use leftovers::{transforms_from, Transform};
+pub use lint::{lint_catalog, Check, Finding, LintReport, Severity};
+pub use meal_data::{Category, Cuisine, Ingredient, IngredientId};
+pub use nutrition::Nutrition;
+pub use pack::{merge_pack, parse_pack};
+pub use prices::SERVINGS;
+pub use seasons::Months;
+pub use times::Minutes;
+pub use weights::DEFAULT_WEIGHT;
+
+#[cfg(debug_assertions)]
+pub use meta::assert_meta_coverage;
+
+/// Panics naming the first built-in ingredient `needs` picks out that
+/// `table` has no row for.
+#[cfg(test)]
+fn assert_covers(table: impl Iterator<Item = IngredientId>, what: &str, needs: impl Fn(&Ingredient) -> bool) {
+    let rows: std::collections::HashSet<IngredientId> = table.collect();
+    for ing in meal_data::INGREDIENTS.iter().filter(|i| needs(i)) {
+        assert!(rows.contains(&ing.id), "{what} missing entry for: {}", ing.id.as_str());
+    }
+}
@@ -0,0 +1,157 @@
+// Nutrition — approximate per-serving values, parallel to INGREDIENTS
+//
+// One serving is what `buy_amount` feeds divided by four: about 6 oz of
+// cooked protein, a cup of starch or a side-dish portion of veg.
+
+use std::ops::AddAssign;
+
+use serde::Deserialize;
+
+#[allow(clippy::wildcard_imports)]
+use super::meal_data::ids::*;
+use super::meal_data::IngredientId;
+
+/// Calories in kcal, macros and fiber in grams, sodium in milligrams.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Nutrition {
+    pub calories: u32,
+    pub protein: u32,
+    pub carbs: u32,
+    pub fat: u32,
+    pub fiber: u32,
+    pub sodium: u32,
+}
+
+impl AddAssign for Nutrition {
+    fn add_assign(&mut self, o: Nutrition) {
+        self.calories += o.calories;
+        self.protein += o.protein;
+        self.carbs += o.carbs;
+        self.fat += o.fat;
+        self.fiber += o.fiber;
+        self.sodium += o.sodium;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IngredientNutrition {
+    pub id: IngredientId,
+    pub per_serving: Nutrition,
+}
+
+macro_rules! nutrition_table {
+    ($($id:ident => [$kcal:literal, $p:literal, $c:literal, $f:literal, $fib:literal, $na:literal],)*) => {
+        pub static INGREDIENT_NUTRITION: &[IngredientNutrition] = &[$(
+            IngredientNutrition {
+                id: $id,
+                per_serving: Nutrition { calories: $kcal, protein: $p, carbs: $c, fat: $f, fiber: $fib, sodium: $na },
+            },
+        )*];
+    };
+}
+
+// id => [kcal, protein g, carbs g, fat g, fiber g, sodium mg]
+nutrition_table! {
+    // Proteins
+    chicken_breast => [280, 53, 0, 6, 0, 125],
+    chicken_thighs => [330, 42, 0, 17, 0, 150],
+    drumsticks => [310, 40, 0, 16, 0, 160],
+    steak => [460, 46, 0, 29, 0, 110],
+    ground_beef => [410, 38, 0, 28, 0, 120],
+    ground_turkey => [340, 36, 3, 19, 1, 520],
+    pork_chops => [350, 46, 0, 17, 0, 110],
+    pork_tenderloin => [240, 44, 0, 6, 0, 100],
+    sausage => [430, 24, 4, 35, 0, 1100],
+    salmon => [350, 38, 0, 21, 0, 105],
+    shrimp => [200, 40, 2, 3, 0, 800],
+    tuna => [220, 50, 0, 2, 0, 80],
+    white_fish => [200, 44, 0, 2, 0, 110],
+    eggs => [215, 18, 1, 15, 0, 210],
+    bacon => [270, 18, 1, 21, 0, 960],
+    // Starches
+    jasmine_rice => [205, 4, 45, 0, 1, 5],
+    cilantro_lime_rice => [220, 4, 45, 3, 1, 300],
+    fried_rice => [330, 8, 50, 11, 2, 820],
+    rice_pilaf => [240, 5, 42, 5, 1, 560],
+    black_beans_rice => [290, 11, 56, 2, 8, 480],
+    mashed_potatoes => [240, 4, 35, 9, 3, 650],
+    roasted_potatoes => [200, 4, 32, 7, 4, 300],
+    baked_potato => [160, 4, 37, 0, 4, 20],
+    twice_baked_potato => [300, 9, 33, 15, 3, 560],
+    au_gratin_potatoes => [320, 12, 28, 19, 2, 720],
+    hash_browns => [270, 3, 32, 15, 3, 420],
+    sweet_potato => [180, 4, 41, 0, 7, 70],
+    fries => [310, 4, 41, 15, 4, 380],
+    bread_rolls => [170, 5, 30, 3, 1, 300],
+    garlic_bread => [250, 6, 28, 13, 1, 380],
+    naan => [260, 9, 45, 5, 2, 420],
+    tortillas => [280, 8, 46, 7, 3, 680],
+    cornbread => [260, 5, 36, 10, 2, 430],
+    pasta => [220, 8, 43, 1, 3, 5],
+    egg_noodles => [220, 7, 40, 3, 2, 10],
+    couscous => [175, 6, 36, 0, 2, 10],
+    quinoa => [220, 8, 39, 4, 5, 15],
+    mac_cheese => [380, 14, 40, 18, 2, 780],
+    yellow_rice => [210, 4, 44, 2, 1, 520],
+    polenta => [200, 5, 30, 7, 2, 430],
+    stuffing => [350, 6, 44, 17, 3, 1000],
+    pita => [165, 6, 33, 1, 1, 320],
+    biscuits => [220, 4, 27, 10, 1, 580],
+    mexican_rice => [230, 4, 42, 5, 2, 560],
+    creamed_corn => [185, 4, 46, 1, 3, 730],
+    // Vegetables
+    broccoli => [55, 4, 11, 1, 5, 60],
+    green_beans => [45, 2, 10, 0, 4, 240],
+    mixed_salad => [90, 2, 8, 6, 3, 180],
+    corn => [130, 5, 29, 2, 3, 15],
+    carrots => [90, 1, 16, 3, 5, 140],
+    asparagus => [60, 4, 8, 3, 4, 150],
+    zucchini => [60, 2, 7, 3, 2, 150],
+    roasted_tomatoes => [70, 2, 8, 4, 2, 200],
+    brussels_sprouts => [110, 4, 12, 6, 5, 200],
+    cauliflower => [100, 3, 10, 6, 4, 220],
+    spinach => [70, 5, 7, 4, 4, 180],
+    mushrooms => [90, 3, 6, 6, 2, 200],
+    eggplant => [110, 2, 12, 7, 6, 210],
+    bell_peppers => [90, 2, 12, 4, 3, 170],
+    elote_corn => [230, 6, 28, 12, 3, 420],
+    bok_choy => [50, 2, 4, 3, 2, 320],
+    sugar_snap_peas => [70, 3, 10, 2, 3, 170],
+    edamame => [190, 17, 14, 8, 8, 10],
+    bean_sprouts => [50, 3, 6, 2, 2, 150],
+    cabbage_slaw => [110, 2, 12, 7, 3, 380],
+    cucumber_dill => [80, 3, 6, 5, 1, 240],
+    greek_salad => [200, 5, 10, 16, 3, 560],
+    coleslaw => [190, 1, 14, 15, 2, 260],
+    collard_greens => [120, 7, 10, 6, 5, 640],
+    baked_beans => [240, 12, 52, 3, 10, 870],
+    kale => [90, 4, 9, 5, 3, 190],
+    peas => [120, 8, 21, 1, 7, 115],
+    cucumber_tomato => [70, 2, 9, 4, 2, 200],
+    roasted_squash => [110, 2, 20, 4, 5, 160],
+    grilled_onions => [90, 1, 13, 4, 2, 150],
+    roasted_beets => [100, 3, 17, 3, 4, 220],
+    sauteed_cabbage => [90, 2, 10, 5, 4, 220],
+    pickled_vegetables => [40, 1, 8, 0, 2, 680],
+    avocado_salad => [180, 3, 11, 15, 7, 220],
+    black_eyed_peas => [200, 13, 35, 1, 11, 420],
+    succotash => [180, 7, 32, 4, 7, 300],
+    ratatouille => [130, 3, 15, 7, 5, 380],
+    caesar_salad => [230, 6, 10, 19, 2, 480],
+    garden_salad => [70, 2, 9, 3, 3, 120],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::assert_covers;
+    use crate::content::meal_data::Category;
+
+    /// Sides may go without data (the macro panel notes the gap); proteins
+    /// carry most of a plate's numbers, so they may not.
+    #[test]
+    fn every_protein_has_nutrition() {
+        assert_covers(INGREDIENT_NUTRITION.iter().map(|n| n.id), "IngredientNutrition", |i| i.category == Category::Protein);
+    }
+}
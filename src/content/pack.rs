@@ -0,0 +1,564 @@
+// Content Packs — JSON ingredient data merged over the built-in catalog
+//
+// A pack looks like:
+//
+// {
+//   "name": "Cajun Night",
+//   "cuisines": [
+//     { "id": "cajun", "label": "Cajun / Creole", "sauces": ["creole seasoning"] },
+//     { "id": "bbq", "label": "Smokehouse" }
+//   ],
+//   "ingredients": [
+//     { "id": "dirty_rice", "name": "Dirty Rice", "category": "starch",
+//       "diet": { "flesh": "meat", "contains": [] }, "allergens": [],
+//       "cuisines": { "cajun": ["shrimp", "sausage", "collard_greens"] } }
+//   ],
+//   "pairings": [
+//     { "ingredient": "shrimp", "cuisine": "cajun", "pairs_with": ["dirty_rice"] }
+//   ]
+// }
+//
+// A cuisine entry with a built-in ID overrides its label and sauces; an
+// ingredient with an existing ID replaces that entry. `pairings` adds edges
+// to ingredients the pack doesn't redefine.
+//
+// `diet` is optional: `flesh` is meatless, seafood or meat and `contains`
+// lists any of dairy, egg, gluten. Ingredients without it are hidden by
+// every dietary filter. `allergens` lists any of dairy, egg, gluten,
+// shellfish, fish, soy, tree_nut, peanut, sesame; leaving it out marks the
+// allergens as unknown, which any allergy profile excludes. `nutrition` is
+// an optional per-serving object with calories, protein, carbs, fat, fiber
+// and sodium, as in nutrition.rs. `tags`, `exclusion_groups` and `aliases`
+// are optional string lists using the meta.rs vocabulary. `weight` is the
+// optional selection weight from weights.rs, where 100 is an ordinary item,
+// up to `MAX_WEIGHT`. `price_cents` is the optional cost of one
+// `buy_amount`, as in prices.rs. `minutes` is an optional object with
+// `hands_on` and `total`, as in times.rs; `hands_on` can't exceed `total`,
+// which can't exceed `MAX_MINUTES`. `months` optionally lists the months
+// (1–12) the item is in season, as in seasons.rs; without it the item is in
+// season all year.
+//
+// A pack merges once: loading one whose name is already in the catalog is
+// refused.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+use serde::Deserialize;
+
+use super::allergens::{Allergen, IngredientAllergens};
+use super::catalog::{Catalog, CuisineInfo};
+use super::diet::{Contains, Flesh, IngredientDiet};
+use super::meta::IngredientMeta;
+use super::nutrition::{IngredientNutrition, Nutrition};
+use super::prices::IngredientPrice;
+use super::seasons::{IngredientSeason, Months};
+use super::times::{IngredientTime, Minutes};
+use super::weights::{IngredientWeight, DEFAULT_WEIGHT};
+use super::meal_data::{Category, Cuisine, Ingredient, IngredientId};
+
+/// Longest `total` a pack item may take: a day.
+const MAX_MINUTES: u32 = 24 * 60;
+/// Heaviest selection weight a pack item may carry.
+const MAX_WEIGHT: u32 = 100 * DEFAULT_WEIGHT;
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ContentPack {
+    pub name: String,
+    #[serde(default)]
+    pub cuisines: Vec<PackCuisine>,
+    #[serde(default)]
+    pub ingredients: Vec<PackIngredient>,
+    #[serde(default)]
+    pub pairings: Vec<PackPairing>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PackCuisine {
+    pub id: String,
+    pub label: Option<String>,
+    #[serde(default)]
+    pub sauces: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PackIngredient {
+    pub id: String,
+    pub name: String,
+    pub category: String,
+    pub buy_amount: Option<String>,
+    pub diet: Option<PackDiet>,
+    pub allergens: Option<Vec<String>>,
+    pub nutrition: Option<Nutrition>,
+    pub weight: Option<u32>,
+    pub price_cents: Option<u32>,
+    pub minutes: Option<Minutes>,
+    pub months: Option<Vec<u32>>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub exclusion_groups: Vec<String>,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    pub cuisines: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PackDiet {
+    pub flesh: String,
+    #[serde(default)]
+    pub contains: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PackPairing {
+    pub ingredient: String,
+    pub cuisine: String,
+    pub pairs_with: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PackError {
+    Parse(String),
+    InvalidId(String),
+    DuplicateId(String),
+    UnknownCategory { ingredient: String, category: String },
+    UnknownCuisine { context: String, cuisine: String },
+    UnknownIngredient { context: String, reference: String },
+    NoCuisines(String),
+    UnknownDiet { ingredient: String, value: String },
+    UnknownAllergen { ingredient: String, value: String },
+    OutOfRange { ingredient: String, field: &'static str, problem: String },
+    AlreadyLoaded(String),
+}
+
+impl fmt::Display for PackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PackError::Parse(msg) => write!(f, "invalid JSON: {msg}"),
+            PackError::InvalidId(id) => write!(f, "\"{id}\" is not a valid ID (use lowercase letters, digits and _)"),
+            PackError::DuplicateId(id) => write!(f, "\"{id}\" is declared more than once"),
+            PackError::UnknownCategory { ingredient, category } => {
+                write!(f, "{ingredient}: unknown category \"{category}\" (expected protein, starch or veg)")
+            }
+            PackError::UnknownCuisine { context, cuisine } => write!(f, "{context}: unknown cuisine \"{cuisine}\""),
+            PackError::UnknownIngredient { context, reference } => {
+                write!(f, "{context}: unknown ingredient \"{reference}\"")
+            }
+            PackError::NoCuisines(id) => write!(f, "{id}: ingredient is not tagged for any cuisine"),
+            PackError::UnknownDiet { ingredient, value } => write!(f, "{ingredient}: unknown diet value \"{value}\""),
+            PackError::UnknownAllergen { ingredient, value } => write!(f, "{ingredient}: unknown allergen \"{value}\""),
+            PackError::OutOfRange { ingredient, field, problem } => write!(f, "{ingredient}: {field} {problem}"),
+            PackError::AlreadyLoaded(name) => write!(f, "pack \"{name}\" is already loaded"),
+        }
+    }
+}
+
+pub fn parse_pack(json: &str) -> Result<ContentPack, PackError> {
+    serde_json::from_str(json).map_err(|e| PackError::Parse(e.to_string()))
+}
+
+/// Validates `pack` against `base` and returns the merged catalog, or every
+/// problem found so the author can fix them in one pass.
+pub fn merge_pack(base: &Catalog, pack: &ContentPack) -> Result<Catalog, Vec<PackError>> {
+    let errors = validate(base, pack);
+    if errors.is_empty() {
+        Ok(build(base, pack))
+    } else {
+        Err(errors)
+    }
+}
+
+fn is_valid_id(id: &str) -> bool {
+    !id.is_empty() && id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn validate(base: &Catalog, pack: &ContentPack) -> Vec<PackError> {
+    let mut errors = Vec::new();
+    if base.packs.contains(&pack.name) {
+        errors.push(PackError::AlreadyLoaded(pack.name.clone()));
+    }
+
+    let mut cuisine_ids: HashSet<&str> = base.cuisines.iter().map(|c| c.cuisine.id()).collect();
+    let mut seen = HashSet::new();
+    for c in &pack.cuisines {
+        if !is_valid_id(&c.id) {
+            errors.push(PackError::InvalidId(c.id.clone()));
+        } else if !seen.insert(c.id.as_str()) {
+            errors.push(PackError::DuplicateId(c.id.clone()));
+        }
+        cuisine_ids.insert(&c.id);
+    }
+
+    let mut ingredient_ids: HashSet<&str> = base.ingredients.iter().map(|i| i.id.as_str()).collect();
+    let mut seen = HashSet::new();
+    for i in &pack.ingredients {
+        if !is_valid_id(&i.id) {
+            errors.push(PackError::InvalidId(i.id.clone()));
+        } else if !seen.insert(i.id.as_str()) {
+            errors.push(PackError::DuplicateId(i.id.clone()));
+        }
+        ingredient_ids.insert(&i.id);
+    }
+
+    for i in &pack.ingredients {
+        if Category::from_id(&i.category).is_none() {
+            errors.push(PackError::UnknownCategory { ingredient: i.id.clone(), category: i.category.clone() });
+        }
+        if i.cuisines.is_empty() {
+            errors.push(PackError::NoCuisines(i.id.clone()));
+        }
+        if let Some(d) = &i.diet {
+            check_diet(&mut errors, &i.id, d);
+        }
+        for a in i.allergens.iter().flatten().filter(|a| Allergen::from_id(a).is_none()) {
+            errors.push(PackError::UnknownAllergen { ingredient: i.id.clone(), value: a.clone() });
+        }
+        check_ranges(&mut errors, i);
+        for (cuisine, pairs) in &i.cuisines {
+            check_refs(&mut errors, &i.id, cuisine, pairs, (&cuisine_ids, &ingredient_ids));
+        }
+    }
+
+    for p in &pack.pairings {
+        if !ingredient_ids.contains(p.ingredient.as_str()) {
+            errors.push(PackError::UnknownIngredient {
+                context: "pairings".to_string(),
+                reference: p.ingredient.clone(),
+            });
+        }
+        check_refs(&mut errors, &p.ingredient, &p.cuisine, &p.pairs_with, (&cuisine_ids, &ingredient_ids));
+    }
+
+    errors
+}
+
+fn check_refs(
+    errors: &mut Vec<PackError>,
+    owner: &str,
+    cuisine: &str,
+    pairs: &[String],
+    (cuisine_ids, ingredient_ids): (&HashSet<&str>, &HashSet<&str>),
+) {
+    if !cuisine_ids.contains(cuisine) {
+        errors.push(PackError::UnknownCuisine { context: owner.to_string(), cuisine: cuisine.to_string() });
+    }
+    for r in pairs.iter().filter(|r| !ingredient_ids.contains(r.as_str())) {
+        errors.push(PackError::UnknownIngredient {
+            context: format!("{owner} ({cuisine})"),
+            reference: r.clone(),
+        });
+    }
+}
+
+fn check_ranges(errors: &mut Vec<PackError>, i: &PackIngredient) {
+    let mut out = |field: &'static str, problem: String| {
+        errors.push(PackError::OutOfRange { ingredient: i.id.clone(), field, problem });
+    };
+    if let Some(m) = i.minutes {
+        if m.total > MAX_MINUTES {
+            out("minutes", format!("total {} is over {MAX_MINUTES}", m.total));
+        }
+        if m.hands_on > m.total {
+            out("minutes", format!("hands_on {} is more than total {}", m.hands_on, m.total));
+        }
+    }
+    for m in i.months.iter().flatten().filter(|m| !matches!(m, 1..=12)) {
+        out("months", format!("{m} is not a month (1\u{2013}12)"));
+    }
+    if let Some(w) = i.weight.filter(|&w| w > MAX_WEIGHT) {
+        out("weight", format!("{w} is over {MAX_WEIGHT}"));
+    }
+}
+
+fn check_diet(errors: &mut Vec<PackError>, owner: &str, d: &PackDiet) {
+    let bad_flesh = Flesh::from_id(&d.flesh).is_none().then_some(&d.flesh);
+    let bad_contains = d.contains.iter().filter(|c| Contains::from_id(c).is_none());
+    for value in bad_flesh.into_iter().chain(bad_contains) {
+        errors.push(PackError::UnknownDiet { ingredient: owner.to_string(), value: value.clone() });
+    }
+}
+
+fn leak(s: &str) -> &'static str {
+    Box::leak(s.to_owned().into_boxed_str())
+}
+
+fn build(base: &Catalog, pack: &ContentPack) -> Catalog {
+    let cuisines = merge_cuisines(&base.cuisines, &pack.cuisines);
+
+    let mut ids: HashMap<&str, IngredientId> =
+        base.ingredients.iter().map(|i| (i.id.as_str(), i.id)).collect();
+    for i in &pack.ingredients {
+        if !ids.contains_key(i.id.as_str()) {
+            ids.insert(&i.id, IngredientId(leak(&i.id)));
+        }
+    }
+    let resolve = |c: &str, pairs: &[String]| -> Option<(Cuisine, Vec<IngredientId>)> {
+        let cuisine = cuisines.iter().find(|info| info.cuisine.id() == c)?.cuisine;
+        Some((cuisine, pairs.iter().filter_map(|p| ids.get(p.as_str()).copied()).collect()))
+    };
+
+    let mut items: Vec<Ingredient> = base.ingredients.iter().map(|&&i| i).collect();
+    let mut diets = base.diets.clone();
+    let mut allergens = base.allergens.clone();
+    let mut nutrition = base.nutrition.clone();
+    let mut meta = base.meta.clone();
+    let mut weights = base.weights.clone();
+    let mut prices = base.prices.clone();
+    let mut times = base.times.clone();
+    let mut seasons = base.seasons.clone();
+    for p in &pack.ingredients {
+        let (Some(&id), Some(category)) = (ids.get(p.id.as_str()), Category::from_id(&p.category)) else {
+            continue;
+        };
+        let pairings: Vec<_> = p.cuisines.iter().filter_map(|(c, pairs)| resolve(c, pairs)).collect();
+        let entry = Ingredient {
+            id,
+            name: leak(&p.name),
+            category,
+            buy_amount: p.buy_amount.as_deref().map(leak),
+            cuisines: leak_pairings(pairings),
+        };
+        match items.iter_mut().find(|i| i.id == id) {
+            Some(existing) => *existing = entry,
+            None => items.push(entry),
+        }
+        // A redefined ingredient doesn't inherit the built-in diet entry
+        diets.retain(|d| d.id != id);
+        diets.extend(p.diet.as_ref().and_then(|d| leak_diet(id, d)));
+        allergens.retain(|a| a.id != id);
+        allergens.extend(p.allergens.as_deref().map(|a| leak_allergens(id, a)));
+        nutrition.retain(|n| n.id != id);
+        nutrition.extend(p.nutrition.map(|per_serving| IngredientNutrition { id, per_serving }));
+        meta.retain(|m| m.id != id);
+        meta.push(IngredientMeta {
+            id,
+            tags: leak_strs(&p.tags),
+            exclusion_groups: leak_strs(&p.exclusion_groups),
+            aliases: leak_strs(&p.aliases),
+        });
+        weights.retain(|w| w.id != id);
+        weights.extend(p.weight.map(|weight| IngredientWeight { id, weight }));
+        prices.retain(|x| x.id != id);
+        prices.extend(p.price_cents.map(|cents| IngredientPrice { id, cents }));
+        times.retain(|t| t.id != id);
+        times.extend(p.minutes.map(|minutes| IngredientTime { id, minutes }));
+        seasons.retain(|x| x.id != id);
+        seasons.extend(p.months.as_deref().map(|m| IngredientSeason { id, months: Months::from_list(m) }));
+    }
+
+    for p in &pack.pairings {
+        let Some((cuisine, extra)) = resolve(&p.cuisine, &p.pairs_with) else { continue };
+        if let Some(item) = items.iter_mut().find(|i| i.id.as_str() == p.ingredient) {
+            item.cuisines = extend_pairings(item.cuisines, cuisine, &extra);
+        }
+    }
+
+    let items: &'static [Ingredient] = Box::leak(items.into_boxed_slice());
+    let mut packs = base.packs.clone();
+    packs.push(pack.name.clone());
+    Catalog {
+        ingredients: items.iter().collect(),
+        cuisines,
+        diets,
+        allergens,
+        nutrition,
+        meta,
+        weights,
+        pair_weights: base.pair_weights.clone(),
+        prices,
+        times,
+        seasons,
+        packs,
+    }
+}
+
+fn leak_diet(id: IngredientId, d: &PackDiet) -> Option<IngredientDiet> {
+    let contains: Vec<Contains> = d.contains.iter().filter_map(|c| Contains::from_id(c)).collect();
+    Some(IngredientDiet {
+        id,
+        flesh: Flesh::from_id(&d.flesh)?,
+        contains: Box::leak(contains.into_boxed_slice()),
+    })
+}
+
+fn leak_allergens(id: IngredientId, names: &[String]) -> IngredientAllergens {
+    let list: Vec<Allergen> = names.iter().filter_map(|a| Allergen::from_id(a)).collect();
+    IngredientAllergens { id, allergens: Box::leak(list.into_boxed_slice()) }
+}
+
+fn leak_strs(list: &[String]) -> &'static [&'static str] {
+    Box::leak(list.iter().map(|s| leak(s)).collect::<Vec<_>>().into_boxed_slice())
+}
+
+fn merge_cuisines(base: &[CuisineInfo], pack: &[PackCuisine]) -> Vec<CuisineInfo> {
+    let mut cuisines = base.to_vec();
+    for c in pack {
+        let label = c.label.as_deref().map(leak);
+        let sauces = (!c.sauces.is_empty()).then(|| leak_strs(&c.sauces));
+        if let Some(info) = cuisines.iter_mut().find(|info| info.cuisine.id() == c.id) {
+            info.label = label.unwrap_or(info.label);
+            info.sauces = sauces.unwrap_or(info.sauces);
+        } else {
+            let cuisine = Cuisine::Custom(leak(&c.id));
+            cuisines.push(CuisineInfo {
+                cuisine,
+                label: label.unwrap_or(cuisine.id()),
+                sauces: sauces.unwrap_or(&[]),
+            });
+        }
+    }
+    cuisines
+}
+
+fn leak_pairings(pairings: Vec<(Cuisine, Vec<IngredientId>)>) -> &'static [(Cuisine, &'static [IngredientId])] {
+    let leaked: Vec<(Cuisine, &'static [IngredientId])> = pairings
+        .into_iter()
+        .map(|(c, pairs)| (c, &*Box::leak(pairs.into_boxed_slice())))
+        .collect();
+    Box::leak(leaked.into_boxed_slice())
+}
+
+fn extend_pairings(
+    current: &'static [(Cuisine, &'static [IngredientId])],
+    cuisine: Cuisine,
+    extra: &[IngredientId],
+) -> &'static [(Cuisine, &'static [IngredientId])] {
+    let mut pairings: Vec<(Cuisine, Vec<IngredientId>)> =
+        current.iter().map(|(c, pairs)| (*c, pairs.to_vec())).collect();
+    if !pairings.iter().any(|(c, _)| *c == cuisine) {
+        pairings.push((cuisine, Vec::new()));
+    }
+    if let Some((_, pairs)) = pairings.iter_mut().find(|(c, _)| *c == cuisine) {
+        for id in extra {
+            if !pairs.contains(id) {
+                pairs.push(*id);
+            }
+        }
+    }
+    leak_pairings(pairings)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CAJUN: &str = r#"{
+        "name": "Cajun Night",
+        "cuisines": [
+            { "id": "cajun", "label": "Cajun / Creole", "sauces": ["creole seasoning"] },
+            { "id": "bbq", "label": "Smokehouse" }
+        ],
+        "ingredients": [
+            { "id": "dirty_rice", "name": "Dirty Rice", "category": "starch",
+              "diet": { "flesh": "meat", "contains": [] }, "allergens": [],
+              "minutes": { "hands_on": 15, "total": 40 }, "months": [9, 10, 11], "weight": 120,
+              "cuisines": { "cajun": ["shrimp", "sausage", "collard_greens"] } }
+        ],
+        "pairings": [
+            { "ingredient": "shrimp", "cuisine": "cajun", "pairs_with": ["dirty_rice"] }
+        ]
+    }"#;
+
+    fn merge(json: &str) -> Result<Catalog, Vec<PackError>> {
+        merge_pack(&Catalog::builtin(), &parse_pack(json).map_err(|e| vec![e])?)
+    }
+
+    /// The errors a one-ingredient pack gets, with `fields` spliced into the
+    /// ingredient.
+    fn item_errors(fields: &str) -> Vec<PackError> {
+        let json = format!(
+            r#"{{ "name": "Test", "ingredients": [{{ "id": "dirty_rice", "name": "Dirty Rice", {fields} }}] }}"#
+        );
+        merge(&json).err().unwrap_or_default()
+    }
+
+    #[test]
+    fn a_valid_pack_merges() {
+        let merged = merge(CAJUN).unwrap_or_else(|e| panic!("{e:?}"));
+        assert_eq!(merged.packs, vec!["Cajun Night".to_string()]);
+        let cajun = merged.cuisines.iter().find(|c| c.cuisine.id() == "cajun").expect("cajun added");
+        assert_eq!(cajun.label, "Cajun / Creole");
+        assert_eq!(merged.label(Cuisine::Bbq), "Smokehouse");
+        let rice = merged.ingredients.iter().find(|i| i.id.as_str() == "dirty_rice").expect("dirty rice added");
+        assert_eq!(rice.category, Category::Starch);
+        let shrimp = merged.ingredients.iter().find(|i| i.id.as_str() == "shrimp").expect("shrimp kept");
+        assert!(shrimp.cuisines.iter().any(|(c, pairs)| c.id() == "cajun" && pairs.contains(&rice.id)));
+        assert_eq!(merged.weight(rice.id), 120);
+        assert!(merged.in_season(rice.id, 10) && !merged.in_season(rice.id, 4));
+    }
+
+    #[test]
+    fn loading_a_pack_twice_is_refused() {
+        let once = merge(CAJUN).unwrap_or_else(|e| panic!("{e:?}"));
+        let pack = parse_pack(CAJUN).unwrap_or_else(|e| panic!("{e}"));
+        let errors = merge_pack(&once, &pack).err().unwrap_or_default();
+        assert_eq!(errors, vec![PackError::AlreadyLoaded("Cajun Night".to_string())]);
+    }
+
+    #[test]
+    fn malformed_json_is_a_parse_error() {
+        assert!(matches!(parse_pack("{ \"name\": "), Err(PackError::Parse(_))));
+        assert!(matches!(parse_pack(r#"{ "name": "x", "extra": 1 }"#), Err(PackError::Parse(_))));
+    }
+
+    #[test]
+    fn ids_must_be_valid_and_unique() {
+        let json = r#"{ "name": "Test", "cuisines": [{ "id": "Cajun" }, { "id": "creole" }, { "id": "creole" }] }"#;
+        let errors = merge(json).err().unwrap_or_default();
+        assert_eq!(errors, vec![PackError::InvalidId("Cajun".into()), PackError::DuplicateId("creole".into())]);
+    }
+
+    #[test]
+    fn unknown_names_are_reported() {
+        let errors = item_errors(r#""category": "dessert", "cuisines": { "cajun": ["okra"] }"#);
+        assert_eq!(errors, vec![
+            PackError::UnknownCategory { ingredient: "dirty_rice".into(), category: "dessert".into() },
+            PackError::UnknownCuisine { context: "dirty_rice".into(), cuisine: "cajun".into() },
+            PackError::UnknownIngredient { context: "dirty_rice (cajun)".into(), reference: "okra".into() },
+        ]);
+        let errors = item_errors(r#""category": "starch", "cuisines": {}"#);
+        assert_eq!(errors, vec![PackError::NoCuisines("dirty_rice".into())]);
+    }
+
+    #[test]
+    fn diet_and_allergen_values_are_checked() {
+        let errors = item_errors(
+            r#""category": "starch", "diet": { "flesh": "vegetable", "contains": ["nuts"] }, "allergens": ["pollen"],
+               "cuisines": { "american": [] }"#,
+        );
+        assert_eq!(errors, vec![
+            PackError::UnknownDiet { ingredient: "dirty_rice".into(), value: "vegetable".into() },
+            PackError::UnknownDiet { ingredient: "dirty_rice".into(), value: "nuts".into() },
+            PackError::UnknownAllergen { ingredient: "dirty_rice".into(), value: "pollen".into() },
+        ]);
+    }
+
+    #[test]
+    fn minutes_months_and_weights_are_range_checked() {
+        let errors = item_errors(
+            r#""category": "starch", "minutes": { "hands_on": 50, "total": 20 }, "months": [0, 6, 13],
+               "weight": 20000, "cuisines": { "american": [] }"#,
+        );
+        let fields: Vec<&str> = errors
+            .iter()
+            .map(|e| match e {
+                PackError::OutOfRange { field, .. } => *field,
+                other => panic!("unexpected {other:?}"),
+            })
+            .collect();
+        assert_eq!(fields, vec!["minutes", "months", "months", "weight"]);
+        let errors =
+            item_errors(r#""category": "starch", "minutes": { "hands_on": 5, "total": 5000 }, "cuisines": { "american": [] }"#);
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert!(item_errors(r#""category": "starch", "weight": -1, "cuisines": { "american": [] }"#)
+            .iter()
+            .all(|e| matches!(e, PackError::Parse(_))));
+    }
+}
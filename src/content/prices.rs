@@ -0,0 +1,125 @@
+// Prices — rough grocery cost in cents, parallel to INGREDIENTS
+//
+// A price covers one `buy_amount` (what a dinner for four uses); items
+// without one are priced per dinner's worth. Households edit their own
+// prices in the app; these are the starting point.
+
+#[allow(clippy::wildcard_imports)]
+use super::meal_data::ids::*;
+use super::meal_data::IngredientId;
+
+/// Servings one `buy_amount` feeds, as in nutrition.rs.
+pub const SERVINGS: u32 = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IngredientPrice {
+    pub id: IngredientId,
+    pub cents: u32,
+}
+
+const fn entry(id: IngredientId, cents: u32) -> IngredientPrice {
+    IngredientPrice { id, cents }
+}
+
+pub static INGREDIENT_PRICES: &[IngredientPrice] = &[
+    // Proteins — per buy_amount
+    entry(chicken_breast, 1000),
+    entry(chicken_thighs, 850),
+    entry(drumsticks, 900),
+    entry(steak, 2400),
+    entry(ground_beef, 1375),
+    entry(ground_turkey, 1250),
+    entry(pork_chops, 1000),
+    entry(pork_tenderloin, 900),
+    entry(sausage, 1000),
+    entry(salmon, 2200),
+    entry(shrimp, 2000),
+    entry(tuna, 2600),
+    entry(white_fish, 1400),
+    entry(eggs, 450),
+    entry(bacon, 700),
+    // Starches — per dinner
+    entry(jasmine_rice, 150),
+    entry(cilantro_lime_rice, 250),
+    entry(fried_rice, 400),
+    entry(rice_pilaf, 300),
+    entry(black_beans_rice, 300),
+    entry(mashed_potatoes, 350),
+    entry(roasted_potatoes, 300),
+    entry(baked_potato, 300),
+    entry(twice_baked_potato, 500),
+    entry(au_gratin_potatoes, 550),
+    entry(hash_browns, 300),
+    entry(sweet_potato, 350),
+    entry(fries, 350),
+    entry(bread_rolls, 350),
+    entry(garlic_bread, 400),
+    entry(naan, 450),
+    entry(tortillas, 300),
+    entry(cornbread, 300),
+    entry(pasta, 200),
+    entry(egg_noodles, 250),
+    entry(couscous, 300),
+    entry(quinoa, 450),
+    entry(mac_cheese, 400),
+    entry(yellow_rice, 200),
+    entry(polenta, 300),
+    entry(stuffing, 350),
+    entry(pita, 350),
+    entry(biscuits, 350),
+    entry(mexican_rice, 250),
+    entry(creamed_corn, 300),
+    // Vegetables — per dinner
+    entry(broccoli, 350),
+    entry(green_beans, 350),
+    entry(mixed_salad, 400),
+    entry(corn, 300),
+    entry(carrots, 250),
+    entry(asparagus, 500),
+    entry(zucchini, 350),
+    entry(roasted_tomatoes, 400),
+    entry(brussels_sprouts, 450),
+    entry(cauliflower, 400),
+    entry(spinach, 400),
+    entry(mushrooms, 450),
+    entry(eggplant, 400),
+    entry(bell_peppers, 450),
+    entry(elote_corn, 500),
+    entry(bok_choy, 350),
+    entry(sugar_snap_peas, 450),
+    entry(edamame, 350),
+    entry(bean_sprouts, 250),
+    entry(cabbage_slaw, 300),
+    entry(cucumber_dill, 300),
+    entry(greek_salad, 550),
+    entry(coleslaw, 300),
+    entry(collard_greens, 350),
+    entry(baked_beans, 300),
+    entry(kale, 350),
+    entry(peas, 250),
+    entry(cucumber_tomato, 350),
+    entry(roasted_squash, 400),
+    entry(grilled_onions, 200),
+    entry(roasted_beets, 350),
+    entry(sauteed_cabbage, 250),
+    entry(pickled_vegetables, 350),
+    entry(avocado_salad, 600),
+    entry(black_eyed_peas, 250),
+    entry(succotash, 350),
+    entry(ratatouille, 600),
+    entry(caesar_salad, 500),
+    entry(garden_salad, 400),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::assert_covers;
+
+    /// Built-in items all carry a price, so a budget never silently treats one
+    /// as free; pack items may go without (the cost line names them).
+    #[test]
+    fn every_ingredient_has_a_price() {
+        assert_covers(INGREDIENT_PRICES.iter().map(|p| p.id), "IngredientPrice", |_| true);
+    }
+}
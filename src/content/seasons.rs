@@ -0,0 +1,153 @@
+// Seasons — the months each side is at its best, parallel to INGREDIENTS
+//
+// Months run 1 (January) to 12 and follow a northern-hemisphere market:
+// spring is March to May, summer June to August, autumn September to
+// November and winter December to February. Staples that keep or come
+// frozen (rice, potatoes, bagged peas) are in season all year. Proteins
+// have no entry and never count as out of season.
+
+#[allow(clippy::wildcard_imports)]
+use super::meal_data::ids::*;
+use super::meal_data::IngredientId;
+
+/// A set of months, bit 0 for January.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Months(pub u16);
+
+impl Months {
+    /// Any month outside 1–12 (such as 0 for "not known yet") is in.
+    pub const fn contains(self, month: u32) -> bool {
+        !matches!(month, 1..=12) || self.0 & (1 << (month - 1)) != 0
+    }
+
+    /// Months from a list, ignoring anything outside 1–12.
+    pub fn from_list(months: &[u32]) -> Self {
+        Months(months.iter().filter(|m| matches!(m, 1..=12)).fold(0, |acc, m| acc | 1 << (m - 1)))
+    }
+}
+
+/// `from` through `to`, wrapping past December.
+const fn span(from: u32, to: u32) -> Months {
+    let mut bits = 0;
+    let mut m = from;
+    loop {
+        bits |= 1 << (m - 1);
+        if m == to {
+            return Months(bits);
+        }
+        m = m % 12 + 1;
+    }
+}
+
+const fn and(a: Months, b: Months) -> Months {
+    Months(a.0 | b.0)
+}
+
+const ALL_YEAR: Months = span(1, 12);
+const SPRING: Months = span(3, 5);
+const SUMMER: Months = span(6, 8);
+const AUTUMN: Months = span(9, 11);
+const WINTER: Months = span(12, 2);
+/// Cool-weather greens and roots, autumn through winter.
+const COLD: Months = and(AUTUMN, WINTER);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IngredientSeason {
+    pub id: IngredientId,
+    pub months: Months,
+}
+
+const fn entry(id: IngredientId, months: Months) -> IngredientSeason {
+    IngredientSeason { id, months }
+}
+
+pub static INGREDIENT_SEASONS: &[IngredientSeason] = &[
+    // Starches
+    entry(jasmine_rice, ALL_YEAR),
+    entry(cilantro_lime_rice, ALL_YEAR),
+    entry(fried_rice, ALL_YEAR),
+    entry(rice_pilaf, ALL_YEAR),
+    entry(black_beans_rice, ALL_YEAR),
+    entry(mashed_potatoes, ALL_YEAR),
+    entry(roasted_potatoes, ALL_YEAR),
+    entry(baked_potato, ALL_YEAR),
+    entry(twice_baked_potato, ALL_YEAR),
+    entry(au_gratin_potatoes, ALL_YEAR),
+    entry(hash_browns, ALL_YEAR),
+    entry(sweet_potato, COLD),
+    entry(fries, ALL_YEAR),
+    entry(bread_rolls, ALL_YEAR),
+    entry(garlic_bread, ALL_YEAR),
+    entry(naan, ALL_YEAR),
+    entry(tortillas, ALL_YEAR),
+    entry(cornbread, ALL_YEAR),
+    entry(pasta, ALL_YEAR),
+    entry(egg_noodles, ALL_YEAR),
+    entry(couscous, ALL_YEAR),
+    entry(quinoa, ALL_YEAR),
+    entry(mac_cheese, ALL_YEAR),
+    entry(yellow_rice, ALL_YEAR),
+    entry(polenta, ALL_YEAR),
+    entry(stuffing, COLD),
+    entry(pita, ALL_YEAR),
+    entry(biscuits, ALL_YEAR),
+    entry(mexican_rice, ALL_YEAR),
+    entry(creamed_corn, SUMMER),
+    // Vegetables
+    entry(broccoli, and(COLD, SPRING)),
+    entry(green_beans, span(6, 9)),
+    entry(mixed_salad, ALL_YEAR),
+    entry(corn, span(7, 9)),
+    entry(carrots, ALL_YEAR),
+    entry(asparagus, span(3, 6)),
+    entry(zucchini, SUMMER),
+    entry(roasted_tomatoes, span(7, 9)),
+    entry(brussels_sprouts, span(10, 2)),
+    entry(cauliflower, COLD),
+    entry(spinach, and(SPRING, AUTUMN)),
+    entry(mushrooms, ALL_YEAR),
+    entry(eggplant, span(7, 9)),
+    entry(bell_peppers, ALL_YEAR),
+    entry(elote_corn, span(7, 9)),
+    entry(bok_choy, ALL_YEAR),
+    entry(sugar_snap_peas, span(4, 6)),
+    entry(edamame, ALL_YEAR),
+    entry(bean_sprouts, ALL_YEAR),
+    entry(cabbage_slaw, ALL_YEAR),
+    entry(cucumber_dill, SUMMER),
+    entry(greek_salad, SUMMER),
+    entry(coleslaw, ALL_YEAR),
+    entry(collard_greens, COLD),
+    entry(baked_beans, ALL_YEAR),
+    entry(kale, COLD),
+    entry(peas, ALL_YEAR),
+    entry(cucumber_tomato, SUMMER),
+    entry(roasted_squash, span(9, 12)),
+    entry(grilled_onions, ALL_YEAR),
+    entry(roasted_beets, COLD),
+    entry(sauteed_cabbage, COLD),
+    entry(pickled_vegetables, ALL_YEAR),
+    entry(avocado_salad, ALL_YEAR),
+    entry(black_eyed_peas, ALL_YEAR),
+    entry(succotash, SUMMER),
+    entry(ratatouille, span(7, 9)),
+    entry(caesar_salad, ALL_YEAR),
+    entry(garden_salad, ALL_YEAR),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::assert_covers;
+    use crate::content::meal_data::Category;
+
+    /// Every built-in side has a season, so "in season only" never drops one
+    /// for want of data; no entry may be empty.
+    #[test]
+    fn every_side_has_a_season() {
+        assert_covers(INGREDIENT_SEASONS.iter().map(|s| s.id), "IngredientSeason", |i| i.category != Category::Protein);
+        for s in INGREDIENT_SEASONS {
+            assert!(s.months.0 != 0, "IngredientSeason has no months: {}", s.id.as_str());
+        }
+    }
+}
@@ -0,0 +1,189 @@
+// Times — hands-on and total minutes, parallel to INGREDIENTS
+//
+// `total` runs from starting the item to serving it; `hands_on` is the part
+// of that the cook is busy (chopping, searing, stirring). An item's default
+// row assumes its usual method. Archetypes that cook a protein another way
+// (smoked for a BBQ plate, stir-fried for a noodle bowl) override it in
+// METHOD_TIMES, keyed by archetype ID.
+
+use serde::Deserialize;
+
+#[allow(clippy::wildcard_imports)]
+use super::meal_data::ids::*;
+use super::meal_data::IngredientId;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Minutes {
+    pub hands_on: u32,
+    pub total: u32,
+}
+
+impl Minutes {
+    /// Components cook side by side, so a plate takes as long as its
+    /// slowest item, unless the cook's own work adds up to more. Sums
+    /// saturate, since pack times aren't ours to trust.
+    pub fn plate(items: impl IntoIterator<Item = Minutes>) -> Minutes {
+        let (hands_on, slowest) = items
+            .into_iter()
+            .fold((0u32, 0), |(h, t), m| (h.saturating_add(m.hands_on), t.max(m.total)));
+        Minutes { hands_on, total: slowest.max(hands_on) }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IngredientTime {
+    pub id: IngredientId,
+    pub minutes: Minutes,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MethodTime {
+    pub archetype: &'static str,
+    pub id: IngredientId,
+    pub minutes: Minutes,
+}
+
+const fn entry(id: IngredientId, hands_on: u32, total: u32) -> IngredientTime {
+    IngredientTime { id, minutes: Minutes { hands_on, total } }
+}
+
+const fn method(archetype: &'static str, id: IngredientId, hands_on: u32, total: u32) -> MethodTime {
+    MethodTime { archetype, id, minutes: Minutes { hands_on, total } }
+}
+
+pub static INGREDIENT_TIMES: &[IngredientTime] = &[
+    // Proteins
+    entry(chicken_breast, 10, 30),
+    entry(chicken_thighs, 10, 40),
+    entry(drumsticks, 10, 50),
+    entry(steak, 10, 25),
+    entry(ground_beef, 10, 20),
+    entry(ground_turkey, 10, 20),
+    entry(pork_chops, 10, 25),
+    entry(pork_tenderloin, 10, 35),
+    entry(sausage, 5, 25),
+    entry(salmon, 5, 20),
+    entry(shrimp, 10, 15),
+    entry(tuna, 5, 15),
+    entry(white_fish, 5, 20),
+    entry(eggs, 5, 10),
+    entry(bacon, 5, 20),
+    // Starches
+    entry(jasmine_rice, 5, 25),
+    entry(cilantro_lime_rice, 10, 25),
+    entry(fried_rice, 15, 20),
+    entry(rice_pilaf, 10, 30),
+    entry(black_beans_rice, 10, 30),
+    entry(mashed_potatoes, 15, 35),
+    entry(roasted_potatoes, 10, 45),
+    entry(baked_potato, 5, 60),
+    entry(twice_baked_potato, 30, 105),
+    entry(au_gratin_potatoes, 20, 90),
+    entry(hash_browns, 10, 25),
+    entry(sweet_potato, 5, 50),
+    entry(fries, 5, 30),
+    entry(bread_rolls, 5, 15),
+    entry(garlic_bread, 5, 15),
+    entry(naan, 5, 10),
+    entry(tortillas, 2, 5),
+    entry(cornbread, 10, 35),
+    entry(pasta, 5, 20),
+    entry(egg_noodles, 5, 15),
+    entry(couscous, 5, 10),
+    entry(quinoa, 5, 20),
+    entry(mac_cheese, 15, 30),
+    entry(yellow_rice, 5, 25),
+    entry(polenta, 15, 30),
+    entry(stuffing, 10, 35),
+    entry(pita, 2, 5),
+    entry(biscuits, 10, 25),
+    entry(mexican_rice, 10, 30),
+    entry(creamed_corn, 10, 20),
+    // Vegetables
+    entry(broccoli, 5, 15),
+    entry(green_beans, 5, 15),
+    entry(mixed_salad, 10, 10),
+    entry(corn, 5, 15),
+    entry(carrots, 5, 25),
+    entry(asparagus, 5, 15),
+    entry(zucchini, 5, 15),
+    entry(roasted_tomatoes, 5, 30),
+    entry(brussels_sprouts, 10, 35),
+    entry(cauliflower, 10, 30),
+    entry(spinach, 5, 8),
+    entry(mushrooms, 10, 15),
+    entry(eggplant, 15, 35),
+    entry(bell_peppers, 10, 15),
+    entry(elote_corn, 10, 20),
+    entry(bok_choy, 5, 10),
+    entry(sugar_snap_peas, 5, 8),
+    entry(edamame, 2, 8),
+    entry(bean_sprouts, 5, 5),
+    entry(cabbage_slaw, 15, 15),
+    entry(cucumber_dill, 10, 10),
+    entry(greek_salad, 15, 15),
+    entry(coleslaw, 15, 15),
+    entry(collard_greens, 15, 60),
+    entry(baked_beans, 10, 45),
+    entry(kale, 10, 15),
+    entry(peas, 2, 8),
+    entry(cucumber_tomato, 10, 10),
+    entry(roasted_squash, 10, 45),
+    entry(grilled_onions, 5, 20),
+    entry(roasted_beets, 10, 60),
+    entry(sauteed_cabbage, 10, 20),
+    entry(pickled_vegetables, 5, 5),
+    entry(avocado_salad, 10, 10),
+    entry(black_eyed_peas, 5, 30),
+    entry(succotash, 10, 25),
+    entry(ratatouille, 25, 60),
+    entry(caesar_salad, 10, 10),
+    entry(garden_salad, 10, 10),
+];
+
+pub static METHOD_TIMES: &[MethodTime] = &[
+    // Low and slow on the smoker
+    method("bbq_smoke_plate", chicken_thighs, 15, 105),
+    method("bbq_smoke_plate", drumsticks, 15, 120),
+    method("bbq_smoke_plate", pork_tenderloin, 15, 120),
+    method("bbq_smoke_plate", sausage, 10, 90),
+    // Sliced thin and stir-fried: quick, but all hands
+    method("noodle_stir_fry", chicken_breast, 15, 20),
+    method("noodle_stir_fry", steak, 15, 15),
+    method("noodle_stir_fry", pork_tenderloin, 15, 20),
+    method("noodle_stir_fry", shrimp, 10, 10),
+    method("fried_rice_bowl", chicken_breast, 15, 20),
+    method("fried_rice_bowl", shrimp, 10, 10),
+    // Into the oven with the vegetables and left alone
+    method("sheet_pan_roast", chicken_thighs, 5, 40),
+    method("sheet_pan_roast", sausage, 5, 30),
+    method("sheet_pan_mediterranean", chicken_thighs, 5, 40),
+    method("sheet_pan_mediterranean", salmon, 5, 20),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::archetypes::ARCHETYPES;
+    use crate::content::assert_covers;
+
+    /// Built-in items all carry a time, so a time limit never lets one through
+    /// unmeasured; method rows must name real archetypes.
+    #[test]
+    fn every_ingredient_and_method_is_timed() {
+        assert_covers(INGREDIENT_TIMES.iter().map(|t| t.id), "IngredientTime", |_| true);
+        for m in METHOD_TIMES {
+            assert!(ARCHETYPES.iter().any(|a| a.id == m.archetype), "MethodTime names unknown archetype: {}", m.archetype);
+        }
+    }
+
+    #[test]
+    fn a_plate_takes_its_slowest_item_or_the_cooks_work() {
+        let m = |hands_on, total| Minutes { hands_on, total };
+        assert_eq!(Minutes::plate([m(10, 25), m(5, 60), m(5, 15)]), m(20, 60));
+        assert_eq!(Minutes::plate([m(20, 25), m(20, 25), m(15, 20)]), m(55, 55));
+        assert_eq!(Minutes::plate([]), m(0, 0));
+        assert_eq!(Minutes::plate([m(u32::MAX, u32::MAX), m(10, 20)]), m(u32::MAX, u32::MAX));
+    }
+}
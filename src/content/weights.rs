@@ -0,0 +1,100 @@
+// Selection Weights — how often an ingredient comes up, relative to others
+//
+// 100 is an ordinary weeknight item; the tables only list departures from
+// that. Ingredient weights are relative odds within a slot; pair weights are
+// percentages applied when both items end up on the same plate.
+
+#[allow(clippy::wildcard_imports)]
+use super::meal_data::ids::*;
+use super::meal_data::IngredientId;
+
+pub const DEFAULT_WEIGHT: u32 = 100;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IngredientWeight {
+    pub id: IngredientId,
+    pub weight: u32,
+}
+
+/// A pairing edge that deserves more (or less) than its share; symmetric.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PairWeight {
+    pub a: IngredientId,
+    pub b: IngredientId,
+    pub weight: u32,
+}
+
+const fn entry(id: IngredientId, weight: u32) -> IngredientWeight {
+    IngredientWeight { id, weight }
+}
+
+const fn pair(a: IngredientId, b: IngredientId, weight: u32) -> PairWeight {
+    PairWeight { a, b, weight }
+}
+
+pub static INGREDIENT_WEIGHTS: &[IngredientWeight] = &[
+    // Proteins
+    entry(chicken_breast, 160),
+    entry(chicken_thighs, 140),
+    entry(ground_beef, 140),
+    entry(steak, 70),
+    entry(pork_tenderloin, 70),
+    entry(tuna, 50),
+    entry(bacon, 50),
+    // Starches
+    entry(jasmine_rice, 150),
+    entry(roasted_potatoes, 150),
+    entry(mashed_potatoes, 130),
+    entry(pasta, 130),
+    entry(twice_baked_potato, 30),
+    entry(au_gratin_potatoes, 40),
+    entry(stuffing, 30),
+    entry(polenta, 40),
+    entry(creamed_corn, 50),
+    // Vegetables
+    entry(broccoli, 160),
+    entry(green_beans, 150),
+    entry(mixed_salad, 130),
+    entry(carrots, 120),
+    entry(ratatouille, 40),
+    entry(roasted_beets, 40),
+    entry(collard_greens, 50),
+    entry(succotash, 40),
+    entry(pickled_vegetables, 50),
+];
+
+pub static PAIR_WEIGHTS: &[PairWeight] = &[
+    pair(steak, baked_potato, 180),
+    pair(steak, asparagus, 150),
+    pair(salmon, asparagus, 150),
+    pair(pork_chops, mashed_potatoes, 150),
+    pair(chicken_thighs, jasmine_rice, 140),
+    pair(ground_beef, tortillas, 160),
+    pair(shrimp, fried_rice, 150),
+    pair(bacon, hash_browns, 160),
+    pair(eggs, hash_browns, 160),
+    pair(mac_cheese, collard_greens, 150),
+    pair(cornbread, black_eyed_peas, 150),
+    pair(pasta, garlic_bread, 140),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::meal_data::INGREDIENTS;
+
+    /// Every weighted pair should be a real pairing edge somewhere, or the
+    /// weight can never apply.
+    #[test]
+    fn pair_weights_are_pairing_edges() {
+        let lists = |x: IngredientId, y: IngredientId| {
+            INGREDIENTS
+                .iter()
+                .filter(|i| i.id == x)
+                .any(|i| i.cuisines.iter().any(|(_, pairs)| pairs.contains(&y)))
+        };
+        for p in PAIR_WEIGHTS {
+            assert!(lists(p.a, p.b) || lists(p.b, p.a), "PairWeight is not a pairing edge: {} + {}", p.a.as_str(), p.b.as_str());
+        }
+    }
+}
@@ -0,0 +1,3 @@
+// Library target — shares the content layer with the `mealgen-lint` tool
+
+pub mod content;
@@ -1,24 +1,31 @@
 #![allow(non_snake_case)]
 
 mod components;
-mod content;
 
-use components::pages::{MealGenerator, NotFound};
+use components::pages::{load_served_packs, MealGenerator, NotFound, WeekPlanner};
 use dioxus::prelude::*;
+use mealgen::content;
 
 #[derive(Clone, Routable, Debug, PartialEq)]
 enum Route {
     #[route("/")]
     MealGenerator {},
+    #[route("/plan")]
+    WeekPlanner {},
     #[route("/:..segments")]
     NotFound { segments: Vec<String> },
 }
 
 fn main() {
+    #[cfg(debug_assertions)]
+    content::assert_meta_coverage();
+
     dioxus::launch(App);
 }
 
 fn App() -> Element {
+    use_future(load_served_packs);
+
     rsx! {
         Router::<Route> {}
     }